@@ -103,9 +103,48 @@ fn bench_put_and_data(c: &mut Criterion) {
     group.finish();
 }
 
+fn wide_vertex<const N: usize>(fanout: usize) -> Sodg<N> {
+    let mut graph = Sodg::<N>::empty(fanout + 1);
+    for i in 0..=fanout {
+        graph.add(i);
+    }
+    for i in 1..=fanout {
+        graph.bind(0, i, Label::Alpha(i));
+    }
+    graph
+}
+
+fn bench_wide_vertices(c: &mut Criterion) {
+    let fanouts = [4, 16, 64];
+    let mut group = c.benchmark_group("wide_vertices");
+    for &fanout in &fanouts {
+        group.bench_with_input(BenchmarkId::new("inline", fanout), &fanout, |b, &fanout| {
+            b.iter(|| {
+                let graph = wide_vertex::<64>(black_box(fanout));
+                for i in 1..=fanout {
+                    black_box(graph.kid(black_box(0), black_box(Label::Alpha(i))));
+                }
+            });
+        });
+        group.bench_with_input(
+            BenchmarkId::new("spilled", fanout),
+            &fanout,
+            |b, &fanout| {
+                b.iter(|| {
+                    let graph = wide_vertex::<4>(black_box(fanout));
+                    for i in 1..=fanout {
+                        black_box(graph.kid(black_box(0), black_box(Label::Alpha(i))));
+                    }
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
 criterion_group!(
     name = benches;
     config = Criterion::default().sample_size(20);
-    targets = bench_add_vertices, bench_bind_edges, bench_put, bench_put_and_data,
+    targets = bench_add_vertices, bench_bind_edges, bench_put, bench_put_and_data, bench_wide_vertices,
 );
 criterion_main!(benches);
@@ -0,0 +1,231 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use crate::{Hex, SodgError};
+
+impl Hex {
+    /// Add two integers, see [`Hex::to_i64`].
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// let d = Hex::from(40_i64).plus_int(&Hex::from(2_i64)).unwrap();
+    /// assert_eq!(42, d.to_i64().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If either of them is not an integer or the result overflows,
+    /// an error will be returned.
+    pub fn plus_int(&self, other: &Self) -> Result<Self, SodgError> {
+        self.int(other, "+", i64::checked_add)
+    }
+
+    /// Subtract an integer from this one, see [`Hex::plus_int`].
+    ///
+    /// # Errors
+    ///
+    /// If either of them is not an integer or the result overflows,
+    /// an error will be returned.
+    pub fn minus_int(&self, other: &Self) -> Result<Self, SodgError> {
+        self.int(other, "-", i64::checked_sub)
+    }
+
+    /// Multiply two integers, see [`Hex::plus_int`].
+    ///
+    /// # Errors
+    ///
+    /// If either of them is not an integer or the result overflows,
+    /// an error will be returned.
+    pub fn times_int(&self, other: &Self) -> Result<Self, SodgError> {
+        self.int(other, "*", i64::checked_mul)
+    }
+
+    /// Divide this integer by another one, rounding towards zero.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// let d = Hex::from(7_i64).div_int(&Hex::from(2_i64)).unwrap();
+    /// assert_eq!(3, d.to_i64().unwrap());
+    /// assert!(d.div_int(&Hex::from(0_i64)).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If either of them is not an integer, the divisor is zero,
+    /// or the result overflows, an error will be returned.
+    pub fn div_int(&self, other: &Self) -> Result<Self, SodgError> {
+        self.int(other, "/", i64::checked_div)
+    }
+
+    /// Add two floats, see [`Hex::to_f64`].
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// let d = Hex::from(0.5_f64).plus_float(&Hex::from(0.25_f64)).unwrap();
+    /// assert_eq!(0.75, d.to_f64().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If either of them is not a float or the result is not finite,
+    /// an error will be returned.
+    pub fn plus_float(&self, other: &Self) -> Result<Self, SodgError> {
+        self.float(other, "+", |a, b| a + b)
+    }
+
+    /// Subtract a float from this one, see [`Hex::plus_float`].
+    ///
+    /// # Errors
+    ///
+    /// If either of them is not a float or the result is not finite,
+    /// an error will be returned.
+    pub fn minus_float(&self, other: &Self) -> Result<Self, SodgError> {
+        self.float(other, "-", |a, b| a - b)
+    }
+
+    /// Multiply two floats, see [`Hex::plus_float`].
+    ///
+    /// # Errors
+    ///
+    /// If either of them is not a float or the result is not finite,
+    /// an error will be returned.
+    pub fn times_float(&self, other: &Self) -> Result<Self, SodgError> {
+        self.float(other, "*", |a, b| a * b)
+    }
+
+    /// Divide this float by another one, see [`Hex::plus_float`].
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// let d = Hex::from(1.5_f64).div_float(&Hex::from(0.5_f64)).unwrap();
+    /// assert_eq!(3.0, d.to_f64().unwrap());
+    /// assert!(d.div_float(&Hex::from(0.0_f64)).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If either of them is not a float, the divisor is zero,
+    /// or the result is not finite, an error will be returned.
+    pub fn div_float(&self, other: &Self) -> Result<Self, SodgError> {
+        self.float(other, "/", |a, b| a / b)
+    }
+
+    /// Apply the checked operation `f` to two integers.
+    fn int(
+        &self,
+        other: &Self,
+        op: &'static str,
+        f: fn(i64, i64) -> Option<i64>,
+    ) -> Result<Self, SodgError> {
+        let left = self.to_i64()?;
+        let right = other.to_i64()?;
+        f(left, right)
+            .map(Self::from)
+            .ok_or_else(|| SodgError::Arithmetic {
+                op,
+                left: left.to_string(),
+                right: right.to_string(),
+            })
+    }
+
+    /// Apply the operation `f` to two floats, refusing infinity and NaN.
+    fn float(
+        &self,
+        other: &Self,
+        op: &'static str,
+        f: fn(f64, f64) -> f64,
+    ) -> Result<Self, SodgError> {
+        let left = self.to_f64()?;
+        let right = other.to_f64()?;
+        let r = f(left, right);
+        if r.is_finite() {
+            Ok(Self::from(r))
+        } else {
+            Err(SodgError::Arithmetic {
+                op,
+                left: left.to_string(),
+                right: right.to_string(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculates_integers() {
+        let a = Hex::from(6_i64);
+        let b = Hex::from(-3_i64);
+        assert_eq!(3, a.plus_int(&b).unwrap().to_i64().unwrap());
+        assert_eq!(9, a.minus_int(&b).unwrap().to_i64().unwrap());
+        assert_eq!(-18, a.times_int(&b).unwrap().to_i64().unwrap());
+        assert_eq!(-2, a.div_int(&b).unwrap().to_i64().unwrap());
+        assert!(matches!(a.plus_int(&b).unwrap(), Hex::Bytes(..)));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let e = Hex::from(i64::MAX).plus_int(&Hex::from(1_i64)).unwrap_err();
+        assert_eq!(format!("Can't calculate {} + 1", i64::MAX), e.to_string());
+        assert!(matches!(
+            Hex::from(i64::MIN).div_int(&Hex::from(-1_i64)),
+            Err(SodgError::Arithmetic { op: "/", .. })
+        ));
+    }
+
+    #[test]
+    fn refuses_wrong_length() {
+        assert!(matches!(
+            Hex::from(1_i32).plus_int(&Hex::from(1_i64)),
+            Err(SodgError::HexLength { kind: "INT", .. })
+        ));
+        assert!(Hex::from(1.0_f32).plus_float(&Hex::from(1.0_f64)).is_err());
+    }
+
+    #[test]
+    fn calculates_floats() {
+        let a = Hex::from(1.5_f64);
+        let b = Hex::from(0.5_f64);
+        assert!((2.0 - a.plus_float(&b).unwrap().to_f64().unwrap()).abs() < f64::EPSILON);
+        assert!((1.0 - a.minus_float(&b).unwrap().to_f64().unwrap()).abs() < f64::EPSILON);
+        assert!((0.75 - a.times_float(&b).unwrap().to_f64().unwrap()).abs() < f64::EPSILON);
+        assert!((3.0 - a.div_float(&b).unwrap().to_f64().unwrap()).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn reports_float_overflow() {
+        let e = Hex::from(f64::MAX)
+            .times_float(&Hex::from(2.0_f64))
+            .unwrap_err();
+        assert!(matches!(e, SodgError::Arithmetic { op: "*", .. }));
+        assert!(
+            Hex::from(f64::MAX)
+                .plus_float(&Hex::from(f64::MAX))
+                .is_err()
+        );
+        assert!(
+            Hex::from(-f64::MAX)
+                .minus_float(&Hex::from(f64::MAX))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn reports_float_division_by_zero() {
+        let e = Hex::from(1.5_f64)
+            .div_float(&Hex::from(0.0_f64))
+            .unwrap_err();
+        assert_eq!("Can't calculate 1.5 / 0", e.to_string());
+        assert!(Hex::from(0.0_f64).div_float(&Hex::from(0.0_f64)).is_err());
+    }
+}
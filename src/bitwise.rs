@@ -0,0 +1,230 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use std::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+use crate::{HEX_SIZE, Hex};
+
+impl Hex {
+    /// Make a new `Hex` of `len` bytes, filled by `fill`, without
+    /// touching the heap, if they fit into [`Hex::Bytes`].
+    fn build(len: usize, fill: impl FnOnce(&mut [u8])) -> Self {
+        if len <= HEX_SIZE {
+            let mut bytes = [0; HEX_SIZE];
+            fill(&mut bytes[..len]);
+            Self::Bytes(bytes, len)
+        } else {
+            let mut v = vec![0; len];
+            fill(&mut v);
+            Self::Vector(v)
+        }
+    }
+
+    /// Combine the bytes of two `Hex` one by one, with `f`.
+    ///
+    /// The shorter one is padded with zeros on the left, like a number.
+    fn zip(&self, other: &Self, f: impl Fn(u8, u8) -> u8) -> Self {
+        let (left, right) = (self.bytes(), other.bytes());
+        let len = left.len().max(right.len());
+        let at =
+            |bytes: &[u8], i: usize| (i + bytes.len()).checked_sub(len).map_or(0, |j| bytes[j]);
+        Self::build(len, |out| {
+            for (i, o) in out.iter_mut().enumerate() {
+                *o = f(at(left, i), at(right, i));
+            }
+        })
+    }
+}
+
+macro_rules! bitwise {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl $trait for &Hex {
+            type Output = Hex;
+
+            /// Combine the bytes one by one, padding the shorter
+            /// `Hex` with zeros on the left.
+            fn $method(self, other: Self) -> Hex {
+                self.zip(other, |x, y| x $op y)
+            }
+        }
+
+        impl $trait for Hex {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                (&self).$method(&other)
+            }
+        }
+    };
+}
+
+bitwise!(BitAnd, bitand, &);
+bitwise!(BitOr, bitor, |);
+bitwise!(BitXor, bitxor, ^);
+
+impl Not for &Hex {
+    type Output = Hex;
+
+    /// Flip all bits, for example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// assert_eq!("FF-00", (!&Hex::from(0x00FF_i16)).print());
+    /// ```
+    fn not(self) -> Hex {
+        let b = self.bytes();
+        Hex::build(b.len(), |out| {
+            for (o, x) in out.iter_mut().zip(b) {
+                *o = !x;
+            }
+        })
+    }
+}
+
+impl Not for Hex {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        !&self
+    }
+}
+
+impl Shl<usize> for &Hex {
+    type Output = Hex;
+
+    /// Shift all bits to the left, as in a big-endian number,
+    /// keeping the length and filling the right with zeros, for example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// assert_eq!("0F-F0", (&Hex::from(0x00FF_i16) << 4).print());
+    /// ```
+    fn shl(self, n: usize) -> Hex {
+        let b = self.bytes();
+        let (skip, bits) = (n / 8, n % 8);
+        let at = |i: usize| i.checked_add(skip).and_then(|j| b.get(j)).copied();
+        Hex::build(b.len(), |out| {
+            for (i, o) in out.iter_mut().enumerate() {
+                let hi = at(i).unwrap_or(0);
+                *o = if bits == 0 {
+                    hi
+                } else {
+                    (hi << bits) | (at(i + 1).unwrap_or(0) >> (8 - bits))
+                };
+            }
+        })
+    }
+}
+
+impl Shl<usize> for Hex {
+    type Output = Self;
+
+    fn shl(self, n: usize) -> Self {
+        &self << n
+    }
+}
+
+impl Shr<usize> for &Hex {
+    type Output = Hex;
+
+    /// Shift all bits to the right, as in a big-endian number,
+    /// keeping the length and filling the left with zeros, for example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// assert_eq!("00-0F", (&Hex::from(0x00FF_i16) >> 4).print());
+    /// ```
+    fn shr(self, n: usize) -> Hex {
+        let b = self.bytes();
+        let (skip, bits) = (n / 8, n % 8);
+        let at = |i: usize| i.checked_sub(skip).and_then(|j| b.get(j)).copied();
+        Hex::build(b.len(), |out| {
+            for (i, o) in out.iter_mut().enumerate() {
+                let lo = at(i).unwrap_or(0);
+                *o = if bits == 0 {
+                    lo
+                } else {
+                    let prev = i.checked_sub(1).and_then(at).unwrap_or(0);
+                    (lo >> bits) | (prev << (8 - bits))
+                };
+            }
+        })
+    }
+}
+
+impl Shr<usize> for Hex {
+    type Output = Self;
+
+    fn shr(self, n: usize) -> Self {
+        &self >> n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr as _;
+
+    use super::*;
+
+    #[test]
+    fn combines_bytes() {
+        let a = Hex::from_str("F0-F0").unwrap();
+        let b = Hex::from_str("FF-00").unwrap();
+        assert_eq!("F0-00", (&a & &b).print());
+        assert_eq!("FF-F0", (&a | &b).print());
+        assert_eq!("0F-F0", (a ^ b).print());
+    }
+
+    #[test]
+    fn pads_shorter_bytes() {
+        let a = Hex::from_str("12-34-56").unwrap();
+        let b = Hex::from_str("FF").unwrap();
+        assert_eq!("00-00-56", (&a & &b).print());
+        assert_eq!("12-34-FF", (&b | &a).print());
+    }
+
+    #[test]
+    fn keeps_small_results_inline() {
+        let a = Hex::from(-1_i64);
+        assert!(matches!(&a & &a, Hex::Bytes(..)));
+        assert!(matches!(!&a, Hex::Bytes(..)));
+        assert!(matches!(&a << 3, Hex::Bytes(..)));
+        assert_eq!(0, (!a).to_i64().unwrap());
+    }
+
+    #[test]
+    fn combines_long_bytes() {
+        let a = Hex::from_slice(&[0xAA; 20]);
+        let b = Hex::from_slice(&[0x55; 20]);
+        assert_eq!(Hex::from_slice(&[0xFF; 20]), &a | &b);
+        assert_eq!(Hex::from_slice(&[0x55; 20]), !a);
+    }
+
+    #[test]
+    fn shifts_like_numbers() {
+        for n in [0, 1, 7, 8, 9, 31, 63] {
+            let d = Hex::from(0x0123_4567_89AB_CDEF_i64);
+            assert_eq!(0x0123_4567_89AB_CDEF_i64 << n, (&d << n).to_i64().unwrap());
+            assert_eq!(
+                (0x0123_4567_89AB_CDEF_u64 >> n).cast_signed(),
+                (&d >> n).to_i64().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn shifts_everything_out() {
+        let d = Hex::from_str("FF-FF").unwrap();
+        assert_eq!("00-00", (&d << 16).print());
+        assert_eq!("00-00", (&d >> usize::MAX).print());
+        assert_eq!("00-00", (d << usize::MAX).print());
+    }
+
+    #[test]
+    fn shifts_long_bytes() {
+        let d = Hex::from_slice(&[0x01; 12]);
+        assert_eq!(Hex::from_slice(&[0x02; 12]).tail(1), (&d << 1).tail(1));
+        assert_eq!(0x00, (&d >> 1)[0]);
+        assert_eq!(0x80, (&d >> 1)[1]);
+    }
+}
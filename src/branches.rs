@@ -0,0 +1,415 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use std::collections::{HashMap, HashSet};
+
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::{
+    BRANCH_NONE, BRANCH_STATIC, Collector, Label, Members, Persistence, Sodg, SodgError, Vertex,
+};
+
+impl<const N: usize> Sodg<N> {
+    /// Find the branch, which vertices `v1` and `v2` will belong to,
+    /// when an edge is made between them.
+    ///
+    /// # Errors
+    ///
+    /// If there are no free branches left, an `Err` will be returned.
+    ///
+    /// If both of them are static, `None` is returned, meaning that
+    /// a fresh branch will be claimed by [`Sodg::enter`]. If both of them
+    /// are in different branches, the larger one is returned.
+    pub(crate) fn plan(&self, v1: usize, v2: usize) -> Result<Option<usize>, SodgError> {
+        let ours = self.vertices[v1].branch;
+        let theirs = self.vertices[v2].branch;
+        if ours == BRANCH_STATIC && theirs == BRANCH_STATIC {
+            if self.available() == 0 {
+                return Err(SodgError::BranchesFull);
+            }
+            return Ok(None);
+        }
+        if ours == BRANCH_STATIC
+            || (theirs != BRANCH_STATIC && self.branches[theirs].len() > self.branches[ours].len())
+        {
+            return Ok(Some(theirs));
+        }
+        Ok(Some(ours))
+    }
+
+    /// Move the vertices among `vs` to the branch `b`, or
+    /// to a fresh one, if `b` is `None`.
+    ///
+    /// Static vertices join the branch alone, while other
+    /// vertices bring their entire branches with them.
+    pub(crate) fn enter(&mut self, b: Option<usize>, vs: &[usize]) {
+        let b = b.unwrap_or_else(|| self.claim());
+        self.touch_branch(b);
+        for v in vs {
+            self.touch(*v);
+            let vtx = &mut self.vertices[*v];
+            if vtx.branch == BRANCH_STATIC {
+                vtx.branch = b;
+                self.branches[b].push(*v);
+                if vtx.persistence == Persistence::Stored {
+                    self.stores[b] += 1;
+                }
+            } else if vtx.branch != b {
+                let other = vtx.branch;
+                self.unite(b, other);
+            }
+        }
+    }
+
+    /// Move all vertices of the branch `other` to the branch `b`,
+    /// together with the data they wait for.
+    fn unite(&mut self, b: usize, other: usize) {
+        self.touch_branch(b);
+        self.touch_branch(other);
+        let members = std::mem::replace(&mut self.branches[other], Members::new());
+        for m in members.iter() {
+            self.touch(m);
+            self.vertices[m].branch = b;
+            self.branches[b].push(m);
+        }
+        self.stores[b] += self.stores[other];
+        self.stores[other] = 0;
+        self.idle.push(other);
+        #[cfg(debug_assertions)]
+        trace!(
+            "#bind: branch no.{other} joined branch no.{b} with {} vertices",
+            members.len()
+        );
+    }
+
+    /// How many more branches may be claimed.
+    const fn available(&self) -> usize {
+        let used = self.branches.len() - 2 - self.idle.len();
+        self.max_branches.saturating_sub(used)
+    }
+
+    /// Take a free branch, either the one recently destroyed
+    /// or a brand new one.
+    ///
+    /// It must be checked by [`Sodg::available`] in advance.
+    fn claim(&mut self) -> usize {
+        if let Some(b) = self.idle.pop() {
+            self.touch_branch(b);
+            return b;
+        }
+        self.branches.push(Members::new());
+        self.stores.push(0);
+        self.branches.len() - 1
+    }
+
+    /// Count off one piece of data, which was read from the branch `b`, and
+    /// destroy the branch, if there is no more data to wait for.
+    ///
+    /// The data of static vertices is not counted, since they are never destroyed.
+    pub(crate) fn release(&mut self, b: usize) {
+        if b == BRANCH_STATIC {
+            return;
+        }
+        self.touch_branch(b);
+        let s = &mut self.stores[b];
+        *s -= 1;
+        if *s > 0 || self.collector == Collector::Tracing {
+            return;
+        }
+        let members = std::mem::replace(&mut self.branches[b], Members::new());
+        for v in members.iter() {
+            self.touch(v);
+            self.vertices[v].branch = BRANCH_NONE;
+            self.vertices[v].collected = true;
+        }
+        #[cfg(debug_assertions)]
+        trace!(
+            "#data: branch no.{} destroyed {} vertices as garbage: {}",
+            b,
+            members.len(),
+            members
+                .iter()
+                .map(|v| format!("ν{v}"))
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+        self.alive -= members.len();
+        for m in members.iter() {
+            self.arrows -= self.unlink_all(m);
+        }
+        for v in members.iter() {
+            self.free(v);
+        }
+        self.idle.push(b);
+        let gone: Vec<usize> = members.iter().collect();
+        self.bury(&gone);
+        self.notify(|o| o.on_collect(&gone));
+    }
+
+    /// Take the vertex `v`, which used to be `before`, out of its branch,
+    /// when it's being removed together with the `incoming` edges.
+    ///
+    /// Both the parents and the kids of `v` are the anchors of [`Sodg::split`],
+    /// that's why the kids not reachable anymore are kept alive in branches
+    /// of their own, exactly as after [`Sodg::unbind`].
+    ///
+    /// # Errors
+    ///
+    /// If the branch has to be split and there are no free branches left,
+    /// an `Err` will be returned and the branch stays intact.
+    pub(crate) fn leave(
+        &mut self,
+        v: usize,
+        before: &Vertex<N>,
+        incoming: &[(usize, Label)],
+    ) -> Result<(), SodgError> {
+        let branch = before.branch;
+        if branch == BRANCH_STATIC {
+            return Ok(());
+        }
+        self.touch_branch(branch);
+        let stored = usize::from(before.persistence == Persistence::Stored);
+        self.stores[branch] -= stored;
+        let members: Vec<usize> = self.branches[branch].iter().filter(|m| *m != v).collect();
+        self.branches[branch] = Members::from_vec(members);
+        let anchors: Vec<usize> = incoming
+            .iter()
+            .map(|(u, _)| *u)
+            .chain(before.edges.iter().map(|(_, to)| *to))
+            .collect();
+        if let Err(e) = self.split(branch, &anchors) {
+            self.branches[branch].push(v);
+            self.stores[branch] += stored;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Take the vertices `gone`, destroyed by [`Sodg::collect`], out of the branch `b`.
+    ///
+    /// The rest of the branch is split into pieces, all of which are kept alive.
+    /// If there are not enough free branches for them, the branch stays whole,
+    /// which only delays its destruction by [`Sodg::data`].
+    pub(crate) fn sweep(&mut self, b: usize, gone: &HashSet<usize>) {
+        self.touch_branch(b);
+        let members: Vec<usize> = self.branches[b]
+            .iter()
+            .filter(|m| !gone.contains(m))
+            .collect();
+        if members.is_empty() {
+            self.branches[b] = Members::new();
+            self.stores[b] = 0;
+            self.idle.push(b);
+            return;
+        }
+        self.stores[b] = members
+            .iter()
+            .filter(|m| self.vertices[**m].persistence == Persistence::Stored)
+            .count();
+        self.branches[b] = Members::from_vec(members.clone());
+        let _ = self.split(b, &members);
+    }
+
+    /// Split the branch `b` into pieces, which are not connected by edges anymore.
+    ///
+    /// Every piece is kept alive: the first one with the `anchors` in it
+    /// stays in the branch `b`, while others move to fresh branches.
+    /// The counters of `stores` are recalculated for every piece.
+    ///
+    /// # Errors
+    ///
+    /// If there are not enough free branches for the pieces, an `Err` will be
+    /// returned and nothing will be changed.
+    pub(crate) fn split(&mut self, b: usize, anchors: &[usize]) -> Result<(), SodgError> {
+        self.touch_branch(b);
+        let members: Vec<usize> = self.branches[b].iter().collect();
+        let inside: HashSet<usize> = members.iter().copied().collect();
+        let mut neighbours: HashMap<usize, Vec<usize>> = HashMap::new();
+        for m in &members {
+            for (a, to) in self.vertices[*m].edges.iter() {
+                if inside.contains(to) && !self.is_weak(*a) {
+                    neighbours.entry(*m).or_default().push(*to);
+                    neighbours.entry(*to).or_default().push(*m);
+                }
+            }
+        }
+        let mut seen = HashSet::new();
+        let mut pieces: Vec<Vec<usize>> = vec![];
+        for start in anchors.iter().copied().chain(members) {
+            if !inside.contains(&start) || !seen.insert(start) {
+                continue;
+            }
+            let mut piece = vec![];
+            let mut todo = vec![start];
+            while let Some(m) = todo.pop() {
+                piece.push(m);
+                for n in neighbours.get(&m).into_iter().flatten() {
+                    if seen.insert(*n) {
+                        todo.push(*n);
+                    }
+                }
+            }
+            pieces.push(piece);
+        }
+        if pieces.len() == 1 {
+            return Ok(());
+        }
+        let needed = pieces.len().saturating_sub(1);
+        if self.available() < needed {
+            return Err(SodgError::BranchesFull);
+        }
+        let free: Vec<usize> = (0..needed).map(|_| self.claim()).collect();
+        let targets = std::iter::once(b).chain(free);
+        self.branches[b] = Members::new();
+        self.stores[b] = 0;
+        if pieces.is_empty() {
+            self.idle.push(b);
+        }
+        for (piece, target) in pieces.into_iter().zip(targets) {
+            for m in &piece {
+                self.touch(*m);
+                self.vertices[*m].branch = target;
+            }
+            self.stores[target] = piece
+                .iter()
+                .filter(|m| self.vertices[**m].persistence == Persistence::Stored)
+                .count();
+            self.branches[target] = Members::from_vec(piece);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hex, Label};
+
+    #[test]
+    fn keeps_connected_branch_intact() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(0, 1, Label::Alpha(1));
+        g.unbind(0, Label::Alpha(0));
+        assert_eq!(2, g.branches[2].len());
+        assert_eq!(2, g.len());
+    }
+
+    #[test]
+    fn moves_piece_with_data_to_new_branch() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        g.add(2);
+        g.bind(1, 2, Label::Alpha(0));
+        g.put(2, &Hex::from(42));
+        g.put(0, &Hex::from(7));
+        assert_eq!(2, g.stores[2]);
+        g.unbind(0, Label::Alpha(0));
+        assert_eq!(1, g.stores[2]);
+        assert_eq!(1, g.stores[3]);
+        assert_eq!(2, g.branches[3].len());
+        assert_eq!(3, g.len());
+        g.data(2);
+        assert_eq!(1, g.len());
+    }
+
+    #[test]
+    fn grows_branch_table() {
+        let mut g: Sodg<16> = Sodg::empty(1024);
+        for v in (0..1000).step_by(2) {
+            g.add(v);
+            g.add(v + 1);
+            g.bind(v, v + 1, Label::Alpha(0));
+        }
+        assert_eq!(1000, g.len());
+        assert_eq!(502, g.branches.len());
+    }
+
+    #[test]
+    fn recycles_destroyed_branch() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.limit_branches(1);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        g.put(1, &Hex::from(42));
+        g.add(2);
+        g.add(3);
+        assert!(matches!(
+            g.try_bind(2, 3, Label::Alpha(0)),
+            Err(SodgError::BranchesFull)
+        ));
+        g.data(1);
+        g.bind(2, 3, Label::Alpha(0));
+        assert_eq!(2, g.vertices[2].branch);
+        assert_eq!(3, g.branches.len());
+    }
+
+    #[test]
+    fn keeps_static_vertex_after_data() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.put(1, &Hex::from(42));
+        g.data(1);
+        assert_eq!(2, g.len());
+        assert_eq!(0, g.stores[BRANCH_STATIC]);
+        g.add(2);
+        g.bind(0, 2, Label::Alpha(0));
+        assert_eq!(2, g.vertices[0].branch);
+    }
+
+    #[test]
+    fn counts_data_of_joining_vertex() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.put(1, &Hex::from(42));
+        g.bind(0, 1, Label::Alpha(0));
+        assert_eq!(1, g.stores[2]);
+        g.data(1);
+        assert_eq!(0, g.len());
+    }
+
+    #[test]
+    fn unites_branches() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        for v in 0..5 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(1, 2, Label::Alpha(0));
+        g.bind(3, 4, Label::Alpha(0));
+        g.put(2, &Hex::from(1));
+        g.put(4, &Hex::from(2));
+        g.bind(2, 3, Label::Alpha(0));
+        assert_eq!(5, g.branches[2].len());
+        assert!(g.branches[3].is_empty());
+        assert_eq!(2, g.stores[2]);
+        g.data(2);
+        assert_eq!(5, g.len());
+        g.data(4);
+        assert_eq!(0, g.len());
+    }
+
+    #[test]
+    fn keeps_kid_while_parent_waits() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        for v in 0..4 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(2, 3, Label::Alpha(0));
+        g.put(1, &Hex::from(1));
+        g.put(3, &Hex::from(2));
+        g.bind(0, 2, Label::Alpha(1));
+        g.data(3);
+        assert_eq!(4, g.len());
+        assert_eq!(Some(2), g.kid(0, Label::Alpha(1)));
+    }
+}
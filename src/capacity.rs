@@ -0,0 +1,196 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use emap::Map;
+
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::{BRANCH_NONE, Sodg, SodgError, Vertex};
+
+impl<const N: usize> Sodg<N> {
+    /// Get the number of vertices the graph can hold without reallocating.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Sodg;
+    /// let mut g : Sodg<16> = Sodg::with_capacity(4);
+    /// assert_eq!(4, g.capacity());
+    /// g.add(10);
+    /// assert!(g.capacity() > 10);
+    /// ```
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.vertices.capacity()
+    }
+
+    /// Make sure at least `additional` more vertices may be added,
+    /// by the IDs returned from [`Sodg::next_id`], without reallocating.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Sodg;
+    /// let mut g : Sodg<16> = Sodg::with_capacity(4);
+    /// g.reserve(100);
+    /// assert!(g.capacity() >= 100);
+    /// ```
+    ///
+    /// The IDs may be sparse, that's why the capacity is counted
+    /// from the next ID never returned by [`Sodg::next_id`] yet,
+    /// rather than from the number of vertices alive.
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.next_v + additional;
+        if needed > self.capacity() {
+            self.reallocate(needed);
+        }
+    }
+
+    /// Shrink the capacity of the graph as much as possible.
+    ///
+    /// Vertices are never renumbered, that's why the capacity
+    /// stays above the largest ID of a vertex alive.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Sodg;
+    /// let mut g : Sodg<16> = Sodg::with_capacity(256);
+    /// g.add(0);
+    /// g.add(7);
+    /// g.shrink_to_fit();
+    /// assert_eq!(8, g.capacity());
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        let top = self
+            .vertices
+            .iter()
+            .filter(|(_, vtx)| vtx.branch != BRANCH_NONE)
+            .map(|(v, _)| v + 1)
+            .max()
+            .unwrap_or(0);
+        if top < self.capacity() {
+            self.reallocate(top);
+        }
+    }
+
+    /// Make sure the vertex `v` fits into the graph, doubling
+    /// its capacity, if necessary.
+    ///
+    /// # Errors
+    ///
+    /// If the storage for `v` can't be allocated, an `Err` will be returned
+    /// and nothing will be changed.
+    pub(crate) fn grow(&mut self, v: usize) -> Result<(), SodgError> {
+        let cap = self.capacity();
+        if v >= cap {
+            let needed = v
+                .checked_add(1)
+                .filter(|n| {
+                    n.checked_mul(size_of::<Vertex<N>>())
+                        .is_some_and(|b| isize::try_from(b).is_ok())
+                })
+                .ok_or(SodgError::IdTooBig(v))?;
+            self.reallocate(cap.checked_mul(2).map_or(needed, |c| c.max(needed)));
+        }
+        Ok(())
+    }
+
+    /// Move all vertices to a new storage of the given capacity.
+    fn reallocate(&mut self, cap: usize) {
+        let cap = cap.max(1);
+        for v in cap..self.capacity() {
+            self.touch(v);
+        }
+        if cap < self.capacity() {
+            self.freed.retain(|v| *v < cap);
+        }
+        let mut old = std::mem::replace(
+            &mut self.vertices,
+            Map::with_capacity_some(cap, Vertex::empty()),
+        );
+        for v in 0..old.capacity().min(cap) {
+            match old.get_mut(v) {
+                Some(vtx) => self
+                    .vertices
+                    .insert(v, std::mem::replace(vtx, Vertex::empty())),
+                None => self.vertices.remove(v),
+            }
+        }
+        #[cfg(debug_assertions)]
+        trace!(
+            "#reallocate: capacity changed from {} to {cap}",
+            old.capacity()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hex, Label};
+
+    #[test]
+    fn grows_on_demand() {
+        let mut g: Sodg<16> = Sodg::with_capacity(1);
+        for v in 0..1000 {
+            g.add(v);
+        }
+        g.bind(0, 999, Label::Alpha(0));
+        g.put(999, &Hex::from(42));
+        assert_eq!(1000, g.len());
+        assert_eq!(Some(999), g.kid(0, Label::Alpha(0)));
+        assert_eq!(1024, g.capacity());
+    }
+
+    #[test]
+    fn keeps_vertices_on_shrink() {
+        let mut g: Sodg<16> = Sodg::with_capacity(64);
+        g.add(3);
+        g.add(5);
+        g.bind(3, 5, Label::Alpha(0));
+        g.shrink_to_fit();
+        assert_eq!(6, g.capacity());
+        assert_eq!(Some(5), g.kid(3, Label::Alpha(0)));
+        assert_eq!(2, g.len());
+    }
+
+    #[test]
+    fn reserves_above_sparse_ids() {
+        let mut g: Sodg<16> = Sodg::with_capacity(4);
+        for _ in 0..8 {
+            g.next_id();
+        }
+        g.reserve(10);
+        let cap = g.capacity();
+        for _ in 0..10 {
+            let v = g.next_id();
+            g.add(v);
+        }
+        assert_eq!(cap, g.capacity());
+    }
+
+    #[test]
+    fn refuses_to_grow_too_far() {
+        let mut g: Sodg<16> = Sodg::with_capacity(4);
+        assert!(matches!(
+            g.grow(usize::MAX),
+            Err(SodgError::IdTooBig(usize::MAX))
+        ));
+        assert!(g.grow(usize::MAX / 2).is_err());
+        assert_eq!(4, g.capacity());
+        g.grow(4).unwrap();
+        assert_eq!(8, g.capacity());
+    }
+
+    #[test]
+    fn gives_ids_beyond_capacity() {
+        let mut g: Sodg<16> = Sodg::with_capacity(2);
+        for _ in 0..10 {
+            let v = g.next_id();
+            g.add(v);
+        }
+        assert_eq!(10, g.len());
+    }
+}
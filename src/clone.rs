@@ -5,12 +5,31 @@ use crate::Sodg;
 
 impl<const N: usize> Clone for Sodg<N> {
     /// Make a clone of the graph.
+    ///
+    /// The observers registered by [`Sodg::observe`] are not cloned,
+    /// neither is the transaction started by [`Sodg::begin`].
     fn clone(&self) -> Self {
         Self {
             vertices: self.vertices.clone(),
+            #[cfg(feature = "gc")]
             branches: self.branches.clone(),
+            #[cfg(feature = "gc")]
             stores: self.stores.clone(),
+            #[cfg(feature = "gc")]
+            idle: self.idle.clone(),
+            #[cfg(feature = "gc")]
+            max_branches: self.max_branches,
+            alive: self.alive,
+            arrows: self.arrows,
             next_v: self.next_v,
+            indexed: self.indexed,
+            freed: self.freed.clone(),
+            skipped: self.skipped.clone(),
+            observers: vec![],
+            roots: self.roots.clone(),
+            collector: self.collector,
+            weak: self.weak.clone(),
+            journal: None,
         }
     }
 }
@@ -30,6 +49,17 @@ mod tests {
         assert_eq!(2, c.len());
     }
 
+    #[test]
+    fn shares_data_buffers() {
+        let buf: std::sync::Arc<[u8]> = std::sync::Arc::from(vec![0xFF; 1024]);
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.put(0, &crate::Hex::from_arc(buf.clone()));
+        let c = g.clone();
+        assert_eq!(3, std::sync::Arc::strong_count(&buf));
+        assert_eq!(1024, c.peek(0).unwrap().len());
+    }
+
     #[test]
     #[allow(clippy::redundant_clone)]
     fn makes_an_empty_clone() {
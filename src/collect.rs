@@ -0,0 +1,258 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+#[cfg(feature = "gc")]
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::{BRANCH_NONE, Collector, Sodg, SodgError};
+
+impl<const N: usize> Sodg<N> {
+    /// Choose the way the graph finds its garbage.
+    ///
+    /// By default, it is [`Collector::Surging`]: branches are destroyed
+    /// by [`Sodg::data`]. With [`Collector::Tracing`], nothing is destroyed
+    /// until [`Sodg::collect`] is called, for example:
+    ///
+    /// ```
+    /// use sodg::{Collector, Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.set_collector(Collector::Tracing);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.put(1, &Hex::from(42));
+    /// g.data(1);
+    /// assert_eq!(2, g.len());
+    /// ```
+    ///
+    /// The vertices whose data was read while the collector was
+    /// [`Collector::Tracing`] are not destroyed by [`Sodg::data`]
+    /// after it gets back to [`Collector::Surging`].
+    pub const fn set_collector(&mut self, c: Collector) {
+        self.collector = c;
+    }
+
+    /// Get the way the graph finds its garbage, see [`Sodg::set_collector`].
+    #[must_use]
+    pub const fn collector(&self) -> Collector {
+        self.collector
+    }
+
+    /// Make vertex `v` a root, which is never destroyed by [`Sodg::collect`],
+    /// together with all vertices reachable from it.
+    ///
+    /// # Panics
+    ///
+    /// If [`Sodg::try_set_root`] returns an error, it will panic.
+    pub fn set_root(&mut self, v: usize) {
+        self.try_set_root(v).unwrap();
+    }
+
+    /// Make vertex `v` a root, see [`Sodg::set_root`].
+    ///
+    /// If the vertex is removed by [`Sodg::remove`], it stops being a root.
+    ///
+    /// # Errors
+    ///
+    /// If vertex `v` is absent, an `Err` will be returned.
+    pub fn try_set_root(&mut self, v: usize) -> Result<(), SodgError> {
+        self.vertex(v)?;
+        self.roots.insert(v);
+        Ok(())
+    }
+
+    /// Make vertex `v` an ordinary vertex again, if it was a root.
+    pub fn unset_root(&mut self, v: usize) {
+        self.roots.remove(&v);
+    }
+
+    /// Iterate the roots, see [`Sodg::set_root`].
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.roots.iter().copied()
+    }
+
+    /// Destroy all vertices, which are not reachable through edges from
+    /// the roots, and return their IDs.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Collector, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.set_collector(Collector::Tracing);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.add(3);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(2, 3, Label::Alpha(0));
+    /// g.bind(3, 2, Label::Alpha(0));
+    /// g.set_root(0);
+    /// assert_eq!(vec![2, 3], g.collect());
+    /// assert_eq!(vec![0, 1], g.keys());
+    /// ```
+    ///
+    /// Cycles and vertices without data are destroyed too, unlike with
+    /// [`Collector::Surging`]. It works with any collector and
+    /// in time proportional to the number of vertices alive.
+    pub fn collect(&mut self) -> Vec<usize> {
+        let mut marked = HashSet::new();
+        let mut todo: Vec<usize> = self.roots().filter(|r| self.contains(*r)).collect();
+        while let Some(v) = todo.pop() {
+            if !marked.insert(v) {
+                continue;
+            }
+            todo.extend(
+                self.vertices[v]
+                    .edges
+                    .iter()
+                    .filter(|(a, _)| !self.is_weak(**a))
+                    .map(|(_, to)| *to),
+            );
+        }
+        let gone: Vec<usize> = self.vertices().filter(|v| !marked.contains(v)).collect();
+        if gone.is_empty() {
+            return gone;
+        }
+        #[cfg(feature = "gc")]
+        let mut touched = BTreeSet::new();
+        for v in &gone {
+            self.arrows -= self.unlink_all(*v);
+            self.touch(*v);
+            let vtx = &mut self.vertices[*v];
+            #[cfg(feature = "gc")]
+            touched.insert(vtx.branch);
+            vtx.branch = BRANCH_NONE;
+            vtx.collected = true;
+        }
+        self.alive -= gone.len();
+        for v in &gone {
+            self.free(*v);
+        }
+        self.bury(&gone);
+        #[cfg(feature = "gc")]
+        {
+            let set: HashSet<usize> = gone.iter().copied().collect();
+            for b in touched {
+                if b != crate::BRANCH_STATIC {
+                    self.sweep(b, &set);
+                }
+            }
+        }
+        #[cfg(debug_assertions)]
+        trace!(
+            "#collect: {} vertices not reachable from {} roots destroyed",
+            gone.len(),
+            self.roots.len()
+        );
+        self.notify(|o| o.on_collect(&gone));
+        gone
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dangling, Hex, Label, Liveness};
+
+    #[test]
+    fn collects_unreachable_cycle() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.set_collector(Collector::Tracing);
+        for v in 0..4 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(1, 2, Label::Alpha(0));
+        g.bind(2, 1, Label::Alpha(0));
+        g.bind(3, 1, Label::Alpha(1));
+        g.set_root(0);
+        assert_eq!(vec![3], g.collect());
+        assert_eq!(Liveness::Collected, g.liveness(3));
+        assert_eq!(3, g.len());
+        assert_eq!(3, g.edge_count());
+        assert_eq!(g.edges().count(), g.edge_count());
+        g.unset_root(0);
+        assert_eq!(vec![0, 1, 2], g.collect());
+        assert!(g.is_empty());
+        assert_eq!(0, g.edge_count());
+    }
+
+    #[test]
+    fn keeps_data_while_tracing() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.set_collector(Collector::Tracing);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        g.put(1, &Hex::from(42_i64));
+        assert_eq!(42, g.data(1).unwrap().to_i64().unwrap());
+        g.unbind(0, Label::Alpha(0));
+        assert_eq!(2, g.len());
+        assert_eq!(Collector::Tracing, g.collector());
+    }
+
+    #[test]
+    fn forgets_removed_root() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.add(0);
+        g.set_root(0);
+        g.remove(0, Dangling::Report);
+        assert_eq!(0, g.roots().count());
+        assert!(g.try_set_root(0).is_err());
+    }
+
+    #[test]
+    fn unlinks_parents_of_survivors() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.index_parents();
+        g.set_collector(Collector::Tracing);
+        g.add(0);
+        g.add(1);
+        g.bind(1, 0, Label::Alpha(0));
+        g.set_root(0);
+        assert_eq!(vec![1], g.collect());
+        assert_eq!(0, g.in_degree(0));
+        g.remove(0, Dangling::Report);
+        assert!(g.is_empty());
+    }
+
+    #[test]
+    fn reuses_collected_vertex() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.set_collector(Collector::Tracing);
+        g.add(0);
+        g.add(1);
+        g.add(2);
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(1, 2, Label::Alpha(0));
+        g.set_root(2);
+        assert_eq!(vec![0, 1], g.collect());
+        g.add(0);
+        g.bind(2, 0, Label::Alpha(0));
+        assert_eq!(Some(0), g.kid(2, Label::Alpha(0)));
+        assert!(g.collect().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn splits_branch_of_survivors() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        for v in 0..3 {
+            g.add(v);
+        }
+        g.bind(1, 0, Label::Alpha(0));
+        g.bind(1, 2, Label::Alpha(0));
+        g.put(2, &Hex::from(42_i64));
+        g.set_root(0);
+        g.set_root(2);
+        assert_eq!(vec![1], g.collect());
+        assert_ne!(g.vertices[0].branch, g.vertices[2].branch);
+        g.data(2);
+        assert_eq!(vec![0], g.keys());
+    }
+}
@@ -1,38 +1,85 @@
 // SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
 // SPDX-License-Identifier: MIT
 
+use std::collections::{BTreeMap, BTreeSet};
+
 use emap::Map;
 
-use crate::{Hex, MAX_BRANCHES, Persistence, Sodg, Vertex};
+use crate::{BRANCH_NONE, Collector, Edges, Hex, Persistence, Sodg, Vertex};
+#[cfg(feature = "gc")]
+use crate::{BRANCH_STATIC, INITIAL_BRANCHES, Members};
 
 impl<const N: usize> Sodg<N> {
     /// Make an empty [`Sodg`], with no vertices and no edges.
     ///
-    /// # Panics
-    ///
-    /// May panic if vertices provided to alerts are absent (should never happen, though).
+    /// The `cap` is the initial capacity of the graph, which grows
+    /// when vertices with larger IDs are added.
     #[must_use]
     pub fn empty(cap: usize) -> Self {
+        Self::with_capacity(cap)
+    }
+
+    /// Make an empty [`Sodg`], with room for `cap` vertices.
+    ///
+    /// The capacity is just a hint: the graph grows on demand, doubling
+    /// its capacity every time a vertex doesn't fit, for example:
+    ///
+    /// ```
+    /// use sodg::Sodg;
+    /// let mut g : Sodg<16> = Sodg::with_capacity(2);
+    /// g.add(5);
+    /// assert_eq!(1, g.len());
+    /// ```
+    #[must_use]
+    pub fn with_capacity(cap: usize) -> Self {
+        #[cfg_attr(not(feature = "gc"), allow(unused_mut))]
         let mut g = Self {
-            vertices: Map::with_capacity_some(
-                cap,
-                Vertex {
-                    branch: 0,
-                    data: Hex::empty(),
-                    persistence: Persistence::Empty,
-                    edges: micromap::Map::new(),
-                },
-            ),
-            stores: Map::with_capacity_some(MAX_BRANCHES, 0),
-            branches: Map::with_capacity_some(MAX_BRANCHES, microstack::Stack::new()),
+            vertices: Map::with_capacity_some(cap.max(1), Vertex::empty()),
+            #[cfg(feature = "gc")]
+            stores: Vec::with_capacity(INITIAL_BRANCHES),
+            #[cfg(feature = "gc")]
+            branches: Vec::with_capacity(INITIAL_BRANCHES),
+            #[cfg(feature = "gc")]
+            idle: vec![],
+            #[cfg(feature = "gc")]
+            max_branches: usize::MAX,
+            alive: 0,
+            arrows: 0,
             next_v: 0,
+            indexed: false,
+            freed: vec![],
+            skipped: BTreeMap::new(),
+            observers: vec![],
+            roots: BTreeSet::new(),
+            collector: Collector::Surging,
+            weak: BTreeSet::new(),
+            journal: None,
         };
-        g.branches.insert(0, microstack::Stack::from_vec(vec![0]));
-        g.branches.insert(1, microstack::Stack::from_vec(vec![0]));
+        #[cfg(feature = "gc")]
+        for _ in [BRANCH_NONE, BRANCH_STATIC] {
+            g.stores.push(0);
+            g.branches.push(Members::new());
+        }
         g
     }
 }
 
+impl<const N: usize> Vertex<N> {
+    /// Make a vertex, which doesn't belong to the graph yet.
+    pub(crate) const fn empty() -> Self {
+        Self {
+            branch: BRANCH_NONE,
+            data: Hex::empty(),
+            persistence: Persistence::Empty,
+            edges: Edges::new(),
+            parents: vec![],
+            collected: false,
+            weak: vec![],
+            listed: false,
+        }
+    }
+}
+
 #[test]
 fn makes_an_empty_sodg() {
     let mut g: Sodg<16> = Sodg::empty(256);
@@ -3,9 +3,9 @@
 
 use std::fmt::{self, Debug, Display, Formatter};
 
-use anyhow::{Context as _, Result};
+use itertools::Itertools as _;
 
-use crate::{Persistence, Sodg};
+use crate::{Persistence, Sodg, SodgError};
 
 impl<const N: usize> Display for Sodg<N> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -16,28 +16,27 @@ impl<const N: usize> Display for Sodg<N> {
 impl<const N: usize> Debug for Sodg<N> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let mut lines = vec![];
-        for (v, vtx) in self.vertices.iter() {
-            if vtx.branch == 0 {
-                continue;
-            }
-            let mut attrs = vtx
-                .edges
-                .iter()
-                .map(|e| format!("\n\t{} ➞ ν{}", e.0, e.1))
+        let mut edges = itertools::put_back(self.edges());
+        for v in self.vertices() {
+            let vtx = &self.vertices[v];
+            let mut attrs = edges
+                .peeking_take_while(|e| e.0 == v)
+                .map(|(_, a, to)| format!("\n\t{a} ➞ ν{to}"))
                 .collect::<Vec<String>>();
             if vtx.persistence != Persistence::Empty {
                 attrs.push(format!("{}", vtx.data));
             }
             lines.push(format!("ν{v} -> ⟦{}⟧", attrs.join(", ")));
         }
-        for (b, members) in self.branches.iter() {
+        #[cfg(feature = "gc")]
+        for (b, members) in self.branches.iter().enumerate() {
             if members.is_empty() {
                 continue;
             }
             lines.push(format!(
                 "b{b}: {{{}}}",
                 members
-                    .into_iter()
+                    .iter()
                     .map(|v| format!("ν{v}"))
                     .collect::<Vec<String>>()
                     .join(", ")
@@ -54,11 +53,8 @@ impl<const N: usize> Sodg<N> {
     /// # Errors
     ///
     /// If the vertex is absent, an error may be returned.
-    pub fn v_print(&self, v: usize) -> Result<String> {
-        let vtx = &self
-            .vertices
-            .get(v)
-            .with_context(|| format!("Can't find ν{v}"))?;
+    pub fn v_print(&self, v: usize) -> Result<String, SodgError> {
+        let vtx = self.vertex(v)?;
         let list: Vec<String> = vtx
             .edges
             .iter()
@@ -44,11 +44,9 @@ digraph {
   edge [fontname=\"Arial\"];"
                 .to_string(),
         );
-        for (v, vtx) in self
-            .vertices
-            .iter()
-            .sorted_by_key(|(v, _)| <usize>::clone(v))
-        {
+        let mut edges = itertools::put_back(self.edges());
+        for v in self.vertices() {
+            let vtx = &self.vertices[v];
             lines.push(format!(
                 "  v{v}[shape=circle,label=\"ν{v}\"{}]; {}",
                 if vtx.persistence == Persistence::Empty {
@@ -62,30 +60,19 @@ digraph {
                     format!("/* {} */", vtx.data)
                 },
             ));
-            for e in vtx.edges.iter().sorted_by_key(|e| e.0) {
+            for (_, a, to) in edges
+                .peeking_take_while(|e| e.0 == v)
+                .sorted_by_key(|e| e.1)
+            {
                 lines.push(format!(
-                    "  v{v} -> v{} [label=\"{}\"{}{}];",
-                    e.1,
-                    e.0,
-                    match e.0 {
-                        Label::Greek(g) => {
-                            if *g == 'ρ' || *g == 'σ' {
-                                ",color=gray,fontcolor=gray"
-                            } else {
-                                ""
-                            }
-                        }
-                        _ => {
-                            ""
-                        }
+                    "  v{v} -> v{to} [label=\"{a}\"{}{}];",
+                    match a {
+                        Label::Greek('ρ' | 'σ') => ",color=gray,fontcolor=gray",
+                        _ => "",
                     },
-                    match e.0 {
-                        Label::Greek(g) => {
-                            if *g == 'π' { ",style=dashed" } else { "" }
-                        }
-                        _ => {
-                            ""
-                        }
+                    match a {
+                        Label::Greek('π') => ",style=dashed",
+                        _ => "",
                     }
                 ));
             }
@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use std::collections::BTreeMap;
+
+use itertools::Either;
+
+use crate::{Edges, Label};
+
+impl<const N: usize> Edges<N> {
+    /// Make an empty list of edges.
+    pub(crate) const fn new() -> Self {
+        Self::Inline(micromap::Map::new())
+    }
+
+    /// Add an edge, replacing the one with the same label, and return
+    /// the vertex the replaced edge was pointing to.
+    ///
+    /// If there is no room inline, all edges move to the heap.
+    pub(crate) fn insert(&mut self, a: Label, v: usize) -> Option<usize> {
+        match self {
+            Self::Inline(map) => {
+                if let Some(prev) = map.checked_insert(a, v) {
+                    return prev;
+                }
+                let mut all: BTreeMap<Label, usize> = map.iter().map(|(k, to)| (*k, *to)).collect();
+                all.insert(a, v);
+                *self = Self::Spilled(all);
+                None
+            }
+            Self::Spilled(map) => map.insert(a, v),
+        }
+    }
+
+    /// Remove an edge and return the vertex it was pointing to.
+    pub(crate) fn remove(&mut self, a: &Label) -> Option<usize> {
+        match self {
+            Self::Inline(map) => map.remove(a),
+            Self::Spilled(map) => map.remove(a),
+        }
+    }
+
+    /// Find the vertex the edge is pointing to.
+    pub(crate) fn get(&self, a: &Label) -> Option<&usize> {
+        match self {
+            Self::Inline(map) => map.get(a),
+            Self::Spilled(map) => map.get(a),
+        }
+    }
+
+    /// Remove all edges.
+    pub(crate) fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// How many edges are there?
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Inline(map) => map.len(),
+            Self::Spilled(map) => map.len(),
+        }
+    }
+
+    /// Iterate the edges.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&Label, &usize)> {
+        match self {
+            Self::Inline(map) => Either::Left(map.iter()),
+            Self::Spilled(map) => Either::Right(map.iter()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_few_edges_inline() {
+        let mut e: Edges<4> = Edges::new();
+        for i in 0..4 {
+            e.insert(Label::Alpha(i), i);
+        }
+        assert!(matches!(e, Edges::Inline(_)));
+        assert_eq!(Some(0), e.insert(Label::Alpha(0), 42));
+        assert!(matches!(e, Edges::Inline(_)));
+    }
+
+    #[test]
+    fn spills_to_heap() {
+        let mut e: Edges<4> = Edges::new();
+        for i in 0..100 {
+            assert!(e.insert(Label::Alpha(i), i).is_none());
+        }
+        assert!(matches!(e, Edges::Spilled(_)));
+        assert_eq!(100, e.len());
+        assert_eq!(Some(&42), e.get(&Label::Alpha(42)));
+        assert_eq!(Some(42), e.remove(&Label::Alpha(42)));
+        assert_eq!(99, e.iter().count());
+    }
+}
@@ -0,0 +1,222 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Write as _;
+
+use crate::{Hex, SodgError};
+
+impl Hex {
+    /// Turn it into a Base64 string.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// let d = Hex::from_str_bytes("hello");
+    /// assert_eq!("aGVsbG8=", d.to_base64());
+    /// assert_eq!(d, Hex::from_base64("aGVsbG8=").unwrap());
+    /// ```
+    #[must_use]
+    pub fn to_base64(&self) -> String {
+        openssl::base64::encode_block(self.bytes())
+    }
+
+    /// Make a new `Hex` from a Base64 string, printed by [`Hex::to_base64`].
+    ///
+    /// # Errors
+    ///
+    /// If the string is not a valid Base64, [`SodgError::BadBase64Literal`] will be returned.
+    pub fn from_base64(s: &str) -> Result<Self, SodgError> {
+        if s.is_empty() {
+            return Ok(Self::empty());
+        }
+        Ok(Self::from_vec(
+            openssl::base64::decode_block(s)
+                .map_err(|_| SodgError::BadBase64Literal(s.to_string()))?,
+        ))
+    }
+
+    /// Turn it into a lowercase hexadecimal string, without separators.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// let d = Hex::from_str_bytes("hi!");
+    /// assert_eq!("686921", d.to_hex());
+    /// assert_eq!(d, Hex::from_hex("686921").unwrap());
+    /// ```
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.bytes())
+    }
+
+    /// Make a new `Hex` from a hexadecimal string without separators,
+    /// printed by [`Hex::to_hex`], in either case.
+    ///
+    /// # Errors
+    ///
+    /// If the string is not a valid hexadecimal literal, an error will be returned.
+    pub fn from_hex(s: &str) -> Result<Self, SodgError> {
+        Ok(Self::from_vec(
+            hex::decode(s).map_err(|_| SodgError::BadHexLiteral(s.to_string()))?,
+        ))
+    }
+
+    /// Turn it into an uppercase hexadecimal string, with bytes
+    /// separated by spaces, which is parsed back by `Hex::from_str`.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use std::str::FromStr as _;
+    /// use sodg::Hex;
+    /// let d = Hex::from_str_bytes("hi!");
+    /// assert_eq!("68 69 21", d.print_spaced());
+    /// assert_eq!(d, Hex::from_str("68 69 21").unwrap());
+    /// ```
+    ///
+    /// An empty `Hex` is printed as an empty string.
+    #[must_use]
+    pub fn print_spaced(&self) -> String {
+        self.bytes()
+            .iter()
+            .map(|b| format!("{b:02X}"))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Turn it into a printable string, where valid UTF-8 characters
+    /// stay as they are, while control characters and broken bytes
+    /// are escaped as `\xNN` and the backslash is escaped as `\\`.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// let d = Hex::from_vec(vec![0x41, 0x0A, 0xFF, 0x5C]);
+    /// assert_eq!("A\\x0A\\xFF\\\\", d.to_escaped());
+    /// assert_eq!(d, Hex::from_escaped(&d.to_escaped()).unwrap());
+    /// ```
+    #[must_use]
+    pub fn to_escaped(&self) -> String {
+        let mut out = String::with_capacity(self.len());
+        for chunk in self.bytes().utf8_chunks() {
+            for c in chunk.valid().chars() {
+                if c == '\\' {
+                    out.push_str("\\\\");
+                } else if c.is_control() {
+                    for b in c.to_string().bytes() {
+                        let _ = write!(out, "\\x{b:02X}");
+                    }
+                } else {
+                    out.push(c);
+                }
+            }
+            for b in chunk.invalid() {
+                let _ = write!(out, "\\x{b:02X}");
+            }
+        }
+        out
+    }
+
+    /// Make a new `Hex` from a string printed by [`Hex::to_escaped`].
+    ///
+    /// # Errors
+    ///
+    /// If there is a broken escape sequence in the string,
+    /// an error will be returned.
+    pub fn from_escaped(s: &str) -> Result<Self, SodgError> {
+        let broken = || SodgError::BadHexLiteral(s.to_string());
+        let mut bytes = Vec::with_capacity(s.len());
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                continue;
+            }
+            match chars.next() {
+                Some('\\') => bytes.push(b'\\'),
+                Some('x') => {
+                    let digits: String = chars.by_ref().take(2).collect();
+                    if digits.len() != 2 || !digits.chars().all(|d| d.is_ascii_hexdigit()) {
+                        return Err(broken());
+                    }
+                    bytes.push(u8::from_str_radix(&digits, 16).map_err(|_| broken())?);
+                }
+                _ => return Err(broken()),
+            }
+        }
+        Ok(Self::from_vec(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr as _;
+
+    use super::*;
+
+    fn samples() -> Vec<Hex> {
+        vec![
+            Hex::empty(),
+            Hex::from(42_i64),
+            Hex::from_str_bytes("Привет, \"мир\"!\t\\"),
+            Hex::from_vec((0..=255).collect()),
+            Hex::from_vec(vec![0xE4, 0xBD, 0x00, 0x7F, 0xC3]),
+        ]
+    }
+
+    #[test]
+    fn round_trips_all_encodings() {
+        for d in samples() {
+            assert_eq!(d, Hex::from_base64(&d.to_base64()).unwrap());
+            assert_eq!(d, Hex::from_hex(&d.to_hex()).unwrap());
+            assert_eq!(d, Hex::from_str(&d.print_spaced()).unwrap());
+            assert_eq!(d, Hex::from_str(&d.print()).unwrap());
+            assert_eq!(d, Hex::from_escaped(&d.to_escaped()).unwrap());
+        }
+    }
+
+    #[test]
+    fn prints_empty_data() {
+        let d = Hex::empty();
+        assert_eq!("", d.to_base64());
+        assert_eq!("", d.to_hex());
+        assert_eq!("", d.print_spaced());
+        assert_eq!("", d.to_escaped());
+    }
+
+    #[test]
+    fn keeps_printable_text() {
+        assert_eq!("Ура!", Hex::from_str_bytes("Ура!").to_escaped());
+        assert_eq!(
+            "\\x00\\xC2\\x85",
+            Hex::from_str_bytes("\u{0}\u{85}").to_escaped()
+        );
+    }
+
+    #[test]
+    fn parses_uppercase_hex() {
+        assert_eq!(
+            Hex::from_hex("CAFE").unwrap(),
+            Hex::from_hex("cafe").unwrap()
+        );
+    }
+
+    #[test]
+    fn refuses_broken_text() {
+        assert!(matches!(
+            Hex::from_base64("a-b"),
+            Err(SodgError::BadBase64Literal(ref s)) if s == "a-b"
+        ));
+        assert!(Hex::from_hex("CA-FE").is_err());
+        assert!(Hex::from_hex("ABC").is_err());
+        assert!(Hex::from_escaped("\\x4").is_err());
+        assert!(Hex::from_escaped("\\xZZ").is_err());
+        assert!(Hex::from_escaped("\\x+F").is_err());
+        assert!(Hex::from_escaped("\\n").is_err());
+        assert!(Hex::from_escaped("\\").is_err());
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::string::FromUtf8Error;
+
+use crate::SodgError;
+
+impl Display for SodgError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::VertexAbsent(v) => write!(f, "Can't find ν{v}"),
+            Self::IdTooBig(v) => write!(f, "Can't grow the graph to fit ν{v}"),
+            Self::SelfLoop { v, label } => write!(f, "Can't bind ν{v} to itself by '{label}'"),
+            Self::BranchesFull => f.write_str("There are no free branches left"),
+            Self::Dangling { v, edges } => write!(
+                f,
+                "Can't remove ν{v}, {} edges point to it: {}",
+                edges.len(),
+                edges
+                    .iter()
+                    .map(|(u, a)| format!("ν{u}.{a}"))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            ),
+            Self::NotATree { missed } => write!(
+                f,
+                "Maybe the right graph was not a tree? {} missed: {}",
+                missed.len(),
+                missed
+                    .iter()
+                    .map(|v| format!("ν{v}"))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            ),
+            Self::LabelTooLong(s) => write!(f, "Can't parse more than 8 chars in '{s}'"),
+            Self::BadLabel(s) => write!(f, "Can't parse label '{s}'"),
+            Self::BadHexLiteral(s) => write!(f, "Can't parse data '{s}'"),
+            Self::BadBase64Literal(s) => write!(f, "Can't parse Base64 data '{s}'"),
+            Self::HexLength {
+                kind,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Wrong number of bytes, can't make {kind} (just {actual} while we need {expected})",
+            ),
+            Self::Arithmetic { op, left, right } => {
+                write!(f, "Can't calculate {left} {op} {right}")
+            }
+            Self::BadChar(c) => write!(f, "Can't make a char of 0x{c:X}"),
+            Self::TooBig { kind, value } => {
+                write!(f, "Can't make {kind} of {value} on this platform")
+            }
+            Self::NotUtf8(e) => write!(f, "The string inside Hex is not UTF-8: {e}"),
+            Self::ScriptSyntax { command, position } => {
+                write!(f, "Failure at the command no.{position}: '{command}'")
+            }
+            Self::Script {
+                command,
+                position,
+                source,
+            } => write!(
+                f,
+                "Failure at the command no.{position}: '{command}': {source}"
+            ),
+            Self::Io(e) => write!(f, "I/O failure: {e}"),
+            Self::Encode(e) => write!(f, "Failed to serialize: {e}"),
+            Self::Decode(e) => write!(f, "Failed to deserialize: {e}"),
+            Self::Xml(e) => write!(f, "Failed to build XML: {e}"),
+        }
+    }
+}
+
+impl Error for SodgError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotUtf8(e) => Some(e),
+            Self::Io(e) => Some(e),
+            Self::Encode(e) => Some(e),
+            Self::Decode(e) => Some(e),
+            Self::Xml(e) => Some(e),
+            Self::Script { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<FromUtf8Error> for SodgError {
+    fn from(e: FromUtf8Error) -> Self {
+        Self::NotUtf8(e)
+    }
+}
+
+impl From<std::io::Error> for SodgError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<bincode::error::EncodeError> for SodgError {
+    fn from(e: bincode::error::EncodeError) -> Self {
+        Self::Encode(e)
+    }
+}
+
+impl From<bincode::error::DecodeError> for SodgError {
+    fn from(e: bincode::error::DecodeError) -> Self {
+        Self::Decode(e)
+    }
+}
+
+impl From<xml_builder::XMLError> for SodgError {
+    fn from(e: xml_builder::XMLError) -> Self {
+        Self::Xml(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Label;
+
+    #[test]
+    fn prints_missed_vertices() {
+        let e = SodgError::NotATree {
+            missed: vec![2, 13, 42],
+        };
+        assert!(e.to_string().contains("3 missed: ν2, ν13, ν42"));
+    }
+
+    #[test]
+    fn prints_self_loop() {
+        let e = SodgError::SelfLoop {
+            v: 7,
+            label: Label::Alpha(1),
+        };
+        assert_eq!("Can't bind ν7 to itself by 'α1'", e.to_string());
+    }
+
+    #[test]
+    fn exposes_source() {
+        let e = SodgError::from(std::io::Error::other("boom"));
+        assert!(e.source().is_some());
+        assert!(SodgError::BranchesFull.source().is_none());
+    }
+}
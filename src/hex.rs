@@ -1,15 +1,18 @@
 // SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
 // SPDX-License-Identifier: MIT
 
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::ops::{
     Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
 };
 use std::str::FromStr;
+use std::sync::Arc;
 
-use anyhow::{Context as _, Result};
+use serde::{Deserialize as _, Deserializer, Serialize as _, Serializer};
 
-use crate::{HEX_SIZE, Hex};
+use crate::{HEX_SIZE, Hex, SodgError};
 
 impl Debug for Hex {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -25,6 +28,25 @@ impl PartialEq for Hex {
 
 impl Eq for Hex {}
 
+impl Hash for Hex {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes().hash(state);
+    }
+}
+
+impl PartialOrd for Hex {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hex {
+    /// Compare the bytes lexicographically, no matter how they are stored.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes().cmp(other.bytes())
+    }
+}
+
 impl Display for Hex {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.print().as_str())
@@ -37,6 +59,7 @@ impl Index<usize> for Hex {
     fn index(&self, index: usize) -> &Self::Output {
         match self {
             Self::Vector(v) => &v[index],
+            Self::Shared(..) => &self.bytes()[index],
             Self::Bytes(a, len) => {
                 if index < *len {
                     &a[index]
@@ -52,6 +75,10 @@ impl IndexMut<usize> for Hex {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         match self {
             Self::Vector(v) => &mut v[index],
+            Self::Shared(..) => {
+                *self = Self::from_slice(self.bytes());
+                &mut self[index]
+            }
             Self::Bytes(a, len) => {
                 if index < *len {
                     &mut a[index]
@@ -69,6 +96,7 @@ impl Index<Range<usize>> for Hex {
     fn index(&self, index: Range<usize>) -> &Self::Output {
         match self {
             Self::Vector(v) => &v[index],
+            Self::Shared(..) => &self.bytes()[index],
             Self::Bytes(a, len) => {
                 if index.end <= *len {
                     &a[index]
@@ -86,6 +114,7 @@ impl Index<RangeFrom<usize>> for Hex {
     fn index(&self, index: RangeFrom<usize>) -> &Self::Output {
         match self {
             Self::Vector(v) => &v[index],
+            Self::Shared(..) => &self.bytes()[index],
             Self::Bytes(a, len) => {
                 if index.start <= *len {
                     &a[index.start..*len]
@@ -103,6 +132,7 @@ impl Index<RangeFull> for Hex {
     fn index(&self, index: RangeFull) -> &Self::Output {
         match self {
             Self::Vector(v) => &v[index],
+            Self::Shared(..) => self.bytes(),
             Self::Bytes(a, len) => &a[0..*len],
         }
     }
@@ -114,6 +144,7 @@ impl Index<RangeInclusive<usize>> for Hex {
     fn index(&self, index: RangeInclusive<usize>) -> &Self::Output {
         match self {
             Self::Vector(v) => &v[index],
+            Self::Shared(..) => &self.bytes()[index],
             Self::Bytes(a, len) => {
                 if *index.end() < *len {
                     &a[index]
@@ -131,6 +162,7 @@ impl Index<RangeTo<usize>> for Hex {
     fn index(&self, index: RangeTo<usize>) -> &Self::Output {
         match self {
             Self::Vector(v) => &v[index],
+            Self::Shared(..) => &self.bytes()[index],
             Self::Bytes(a, len) => {
                 if index.end <= *len {
                     &a[index]
@@ -148,6 +180,7 @@ impl Index<RangeToInclusive<usize>> for Hex {
     fn index(&self, index: RangeToInclusive<usize>) -> &Self::Output {
         match self {
             Self::Vector(v) => &v[index],
+            Self::Shared(..) => &self.bytes()[index],
             Self::Bytes(a, len) => {
                 if index.end < *len {
                     &a[index]
@@ -196,6 +229,7 @@ impl Hex {
         match self {
             Self::Vector(v) => v,
             Self::Bytes(array, size) => &array[..*size],
+            Self::Shared(buf, from, to) => &buf[*from..*to],
         }
     }
 
@@ -222,6 +256,7 @@ impl Hex {
         match self {
             Self::Vector(x) => x.len(),
             Self::Bytes(_, size) => *size,
+            Self::Shared(_, from, to) => to - from,
         }
     }
 
@@ -270,6 +305,39 @@ impl Hex {
         }
     }
 
+    /// Create a new [`Hex`] from a shared buffer, without copying it.
+    ///
+    /// Clones of such a [`Hex`], including the ones made by [`crate::Sodg::put`]
+    /// and [`crate::Sodg::data`], share the same buffer, and so do
+    /// the results of [`Hex::tail`]. For example:
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use sodg::Hex;
+    /// let buf: Arc<[u8]> = Arc::from(vec![0xAB; 1 << 20]);
+    /// let d = Hex::from_arc(buf.clone());
+    /// let t = d.tail(1024);
+    /// assert_eq!((1 << 20) - 1024, t.len());
+    /// assert_eq!(3, Arc::strong_count(&buf));
+    /// ```
+    #[must_use]
+    pub fn from_arc(buf: Arc<[u8]>) -> Self {
+        let len = buf.len();
+        Self::Shared(buf, 0, len)
+    }
+
+    /// Move the bytes into a shared buffer, see [`Hex::from_arc`].
+    ///
+    /// Small data, which fits into [`Hex`] without touching the heap,
+    /// stays as it is, since it is cheap to copy anyway.
+    #[must_use]
+    pub fn into_shared(self) -> Self {
+        match self {
+            Self::Vector(v) => Self::from_arc(Arc::from(v)),
+            h => h,
+        }
+    }
+
     /// Create a new [`Hex`] from the bytes composing `&str`.
     ///
     /// For example:
@@ -325,12 +393,11 @@ impl Hex {
     /// # Errors
     ///
     /// If it's impossible to convert to an integer, an error will be returned.
-    pub fn to_i64(&self) -> Result<i64> {
-        let a: &[u8; 8] = &self.bytes().try_into().with_context(|| {
-            format!(
-                "There is not enough bytes, can't make INT (just {} while we need eight)",
-                self.bytes().len(),
-            )
+    pub fn to_i64(&self) -> Result<i64, SodgError> {
+        let a: &[u8; 8] = &self.bytes().try_into().map_err(|_| SodgError::HexLength {
+            kind: "INT",
+            expected: 8,
+            actual: self.len(),
         })?;
         Ok(i64::from_be_bytes(*a))
     }
@@ -348,12 +415,11 @@ impl Hex {
     /// # Errors
     ///
     /// If it's impossible to convert to a float, an error will be returned.
-    pub fn to_f64(&self) -> Result<f64> {
-        let a: &[u8; 8] = &self.bytes().try_into().with_context(|| {
-            format!(
-                "There is not enough bytes, can't make FLOAT (just {} while we need eight)",
-                self.bytes().len(),
-            )
+    pub fn to_f64(&self) -> Result<f64, SodgError> {
+        let a: &[u8; 8] = &self.bytes().try_into().map_err(|_| SodgError::HexLength {
+            kind: "FLOAT",
+            expected: 8,
+            actual: self.len(),
         })?;
         Ok(f64::from_be_bytes(*a))
     }
@@ -371,9 +437,8 @@ impl Hex {
     /// # Errors
     ///
     /// If it's impossible to convert to a UTF-8 string, an error will be returned.
-    pub fn to_utf8(&self) -> Result<String> {
-        String::from_utf8(self.bytes().to_vec())
-            .with_context(|| format!("The string inside Hex is not UTF-8 ({} bytes)", self.len()))
+    pub fn to_utf8(&self) -> Result<String, SodgError> {
+        Ok(String::from_utf8(self.bytes().to_vec())?)
     }
 
     /// Turn it into a hexadecimal string.
@@ -414,6 +479,22 @@ impl Hex {
         }
     }
 
+    /// Calculate SHA-256 digest of the bytes.
+    ///
+    /// It doesn't depend on how the bytes are stored, so it is stable
+    /// across versions and platforms, for example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// let d = Hex::from_str_bytes("abc");
+    /// assert_eq!(0xBA, d.digest()[0]);
+    /// assert_eq!(d.digest(), Hex::from_vec(b"abc".to_vec()).digest());
+    /// ```
+    #[must_use]
+    pub fn digest(&self) -> [u8; 32] {
+        openssl::sha::sha256(self.bytes())
+    }
+
     /// Turn it into a vector of bytes (making a clone).
     #[must_use]
     pub fn to_vec(&self) -> Vec<u8> {
@@ -445,9 +526,26 @@ impl Hex {
     /// let d = Hex::from_str_bytes("Hello, world!");
     /// assert_eq!("world!", d.tail(7).to_utf8().unwrap());
     /// ```
+    ///
+    /// If the data is in a shared buffer, see [`Hex::from_arc`], the
+    /// buffer is not copied.
+    ///
+    /// # Panics
+    ///
+    /// If there are less than `skip` bytes, it will panic.
     #[must_use]
     pub fn tail(&self, skip: usize) -> Self {
-        Self::from_vec(self.bytes()[skip..].to_vec())
+        match self {
+            Self::Shared(buf, from, to) => {
+                assert!(
+                    skip <= to - from,
+                    "Can't skip {skip} bytes of {}",
+                    to - from
+                );
+                Self::Shared(buf.clone(), from + skip, *to)
+            }
+            _ => Self::from_vec(self.bytes()[skip..].to_vec()),
+        }
     }
 
     /// Create a new `Hex`, which is a concatenation of `self` and `h`.
@@ -481,8 +579,34 @@ impl Hex {
                     Self::Vector(v)
                 }
             }
+            Self::Shared(..) => {
+                let mut v = self.to_vec();
+                v.extend_from_slice(h.bytes());
+                Self::Vector(v)
+            }
         }
     }
+
+    /// Serialize the visible part of a shared buffer, see [`Hex::Shared`].
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn save_shared<S: Serializer>(
+        buf: &Arc<[u8]>,
+        from: &usize,
+        to: &usize,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        buf[*from..*to].serialize(s)
+    }
+
+    /// Deserialize a shared buffer, saved by [`Hex::save_shared`].
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn load_shared<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<(Arc<[u8]>, usize, usize), D::Error> {
+        let bytes: Vec<u8> = Vec::deserialize(d)?;
+        let len = bytes.len();
+        Ok((Arc::from(bytes), 0, len))
+    }
 }
 
 impl From<i64> for Hex {
@@ -593,7 +717,7 @@ impl From<bool> for Hex {
 }
 
 impl FromStr for Hex {
-    type Err = anyhow::Error;
+    type Err = SodgError;
 
     /// Create a `Hex` from a `&str` containing a hexadecimal representation of data.
     ///
@@ -621,12 +745,17 @@ impl FromStr for Hex {
     /// assert_eq!(Hex::empty(), d2);
     /// ```
     ///
+    /// The bytes may also be separated by spaces, as printed
+    /// by [`Hex::print_spaced`], or not separated at all, in either case.
+    ///
     /// # Errors
     ///
     /// If it's impossible to convert from a String, an error will be returned.
-    fn from_str(hex: &str) -> std::result::Result<Self, Self::Err> {
-        let s = hex.replace('-', "");
-        Ok(Self::from_vec(hex::decode(s)?))
+    fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        let s = hex.replace(['-', ' '], "");
+        Ok(Self::from_vec(
+            hex::decode(s).map_err(|_| SodgError::BadHexLiteral(hex.to_string()))?,
+        ))
     }
 }
 
@@ -718,6 +847,27 @@ mod tests {
         assert!(ret.is_err());
     }
 
+    #[test]
+    fn explains_wrong_length() {
+        let d = Hex::from_vec([0x01, 0x02].to_vec());
+        assert!(matches!(
+            d.to_i64(),
+            Err(SodgError::HexLength {
+                kind: "INT",
+                expected: 8,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn refuses_broken_literal() {
+        assert!(matches!(
+            Hex::from_str("DE-AD-XX"),
+            Err(SodgError::BadHexLiteral(_))
+        ));
+    }
+
     #[test]
     fn broken_float_from_small_data() {
         let d = Hex::from_vec([0x00].to_vec());
@@ -997,6 +1147,83 @@ mod tests {
         let _ = &a[..=7];
     }
 
+    #[test]
+    fn shares_buffer_in_clones_and_tails() {
+        let buf: Arc<[u8]> = Arc::from(vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09]);
+        let d = Hex::from_arc(buf.clone());
+        let c = d.clone();
+        let t = c.tail(6);
+        assert_eq!(4, Arc::strong_count(&buf));
+        assert_eq!("07-08-09", t.print());
+        assert_eq!(0x08, t[1]);
+        assert_eq!(&[0x08, 0x09], &t[1..]);
+        assert_eq!(Hex::from_slice(&buf), d);
+        assert!(t.tail(3).is_empty());
+    }
+
+    #[test]
+    fn copies_shared_buffer_on_write() {
+        let buf: Arc<[u8]> = Arc::from(vec![0xAB; 16]);
+        let mut d = Hex::from_arc(buf.clone()).tail(12);
+        d[0] = 0x01;
+        assert_eq!("01-AB-AB-AB", d.print());
+        assert_eq!(0xAB, buf[12]);
+        assert_eq!(1, Arc::strong_count(&buf));
+    }
+
+    #[test]
+    fn moves_vector_into_shared_buffer() {
+        let d = Hex::from_vec(vec![0xCA; 32]).into_shared();
+        assert!(matches!(d, Hex::Shared(..)));
+        assert_eq!(32, d.concat(&Hex::empty()).len());
+        assert!(matches!(Hex::from(42).into_shared(), Hex::Bytes(..)));
+    }
+
+    #[test]
+    fn saves_visible_part_of_shared_buffer() {
+        let d = Hex::from_arc(Arc::from(vec![0xEE; 64])).tail(60);
+        let bytes = bincode::serde::encode_to_vec(&d, bincode::config::standard()).unwrap();
+        let (after, _): (Hex, usize) =
+            bincode::serde::decode_from_slice(&bytes, bincode::config::standard()).unwrap();
+        assert_eq!(d, after);
+        assert!(bytes.len() < 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't skip 5 bytes of 4")]
+    fn refuses_to_skip_too_much_of_shared_buffer() {
+        let _ = Hex::from_arc(Arc::from(vec![0x00; 4])).tail(5);
+    }
+
+    #[test]
+    fn hashes_and_orders_by_bytes() {
+        let long = Hex::from_vec(vec![0x01; 12]);
+        let shared = Hex::from_arc(Arc::from(vec![0x01; 13])).tail(1);
+        let mut set = std::collections::HashSet::new();
+        set.insert(long.clone());
+        assert!(set.contains(&shared));
+        let mut sorted = [
+            Hex::from_str("02").unwrap(),
+            Hex::from_str("01-FF").unwrap(),
+            Hex::empty(),
+            long,
+        ];
+        sorted.sort();
+        assert_eq!(
+            vec!["--", "01-01-01-01-01-01-01-01-01-01-01-01", "01-FF", "02"],
+            sorted.iter().map(Hex::print).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn calculates_known_digest() {
+        assert_eq!(
+            "E3-B0-C4-42-98-FC-1C-14-9A-FB-F4-C8-99-6F-B9-24-27-AE-41-E4-64-9B-93-4C-A4-95-99-1B-78-52-B8-55",
+            Hex::from_vec(Hex::empty().digest().to_vec()).print()
+        );
+        assert_ne!(Hex::from(1_i64).digest(), Hex::from(2_i64).digest());
+    }
+
     #[test]
     fn test_from_str_bytes_correctly() {
         let a = Hex::from_str_bytes("Hello, world!");
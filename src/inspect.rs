@@ -3,10 +3,9 @@
 
 use std::collections::HashSet;
 
-use anyhow::{Context as _, Result};
 use itertools::Itertools;
 
-use crate::Sodg;
+use crate::{Sodg, SodgError};
 
 impl<const N: usize> Sodg<N> {
     /// Find an object by the provided locator and print its tree
@@ -17,7 +16,7 @@ impl<const N: usize> Sodg<N> {
     /// # Errors
     ///
     /// If it's impossible to inspect, an error will be returned.
-    pub fn inspect(&self, v: usize) -> Result<String> {
+    pub fn inspect(&self, v: usize) -> Result<String, SodgError> {
         let mut seen = HashSet::new();
         Ok(format!(
             "ν{}\n{}",
@@ -26,36 +25,29 @@ impl<const N: usize> Sodg<N> {
         ))
     }
 
-    fn inspect_v(&self, v: usize, seen: &mut HashSet<usize>) -> Result<Vec<String>> {
+    fn inspect_v(&self, v: usize, seen: &mut HashSet<usize>) -> Result<Vec<String>, SodgError> {
         seen.insert(v);
         let mut lines = vec![];
-        self.vertices
-            .get(v)
-            .with_context(|| format!("Can't find ν{v}"))?
-            .edges
-            .iter()
-            .sorted()
-            .for_each(|e| {
-                let skip = seen.contains(e.1);
-                let line = format!(
-                    "  .{} ➞ ν{}{}",
-                    e.0,
-                    e.1,
-                    if skip {
-                        "…".to_owned()
-                    } else {
-                        String::new()
-                    },
-                );
-                lines.push(line);
-                if !skip {
-                    seen.insert(*e.1);
-                    self.inspect_v(*e.1, seen)
-                        .unwrap()
-                        .iter()
-                        .for_each(|t| lines.push(format!("  {t}")));
-                }
-            });
+        for e in self.vertex(v)?.edges.iter().sorted() {
+            let skip = seen.contains(e.1);
+            let line = format!(
+                "  .{} ➞ ν{}{}",
+                e.0,
+                e.1,
+                if skip {
+                    "…".to_owned()
+                } else {
+                    String::new()
+                },
+            );
+            lines.push(line);
+            if !skip {
+                seen.insert(*e.1);
+                self.inspect_v(*e.1, seen)?
+                    .iter()
+                    .for_each(|t| lines.push(format!("  {t}")));
+            }
+        }
         Ok(lines)
     }
 }
@@ -4,18 +4,20 @@
 use std::fmt::{self, Debug, Display, Formatter};
 use std::str::FromStr;
 
-use anyhow::bail;
 use rstest::rstest;
 
-use crate::Label;
+use crate::{Label, SodgError};
 
 impl FromStr for Label {
-    type Err = anyhow::Error;
+    type Err = SodgError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(if s.starts_with('α') {
             let tail: String = s.chars().skip(1).collect::<Vec<_>>().into_iter().collect();
-            Self::Alpha(tail.parse::<usize>()?)
+            Self::Alpha(
+                tail.parse::<usize>()
+                    .map_err(|_| SodgError::BadLabel(s.to_string()))?,
+            )
         } else if s.len() == 1 {
             Self::Greek(s.chars().next().unwrap())
         } else {
@@ -23,7 +25,7 @@ impl FromStr for Label {
             let mut a: [char; 8] = [' '; 8];
             for (i, c) in v.into_iter().enumerate() {
                 if i > 7 {
-                    bail!("Can't parse more than {} chars", a.len());
+                    return Err(SodgError::LabelTooLong(s.to_string()));
                 }
                 a[i] = c;
             }
@@ -58,3 +60,16 @@ fn parses_and_prints(#[case] txt: &str) {
     let l = Label::from_str(txt).unwrap();
     assert_eq!(txt, l.to_string());
 }
+
+#[test]
+fn refuses_too_long_label() {
+    assert!(matches!(
+        Label::from_str("abcdefghij"),
+        Err(SodgError::LabelTooLong(_))
+    ));
+}
+
+#[test]
+fn refuses_broken_alpha() {
+    assert!(matches!(Label::from_str("αx"), Err(SodgError::BadLabel(_))));
+}
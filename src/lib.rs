@@ -6,7 +6,10 @@
 //! modifications comping from a user (through [`Sodg::add`],
 //! [`Sodg::bind`], and [`Sodg::put`]) and then decides itself when
 //! it's time to delete some vertices (something similar to
-//! "garbage collection").
+//! "garbage collection"). The garbage collection is enabled by the `gc`
+//! feature, which is on by default. Without it, vertices are never deleted
+//! by the graph itself. Also, there is a classic mark-and-sweep
+//! collector, see [`Sodg::collect`] and [`Collector::Tracing`].
 //!
 //! For example, here is how you create a simple
 //! di-graph with two vertices and an edge between them:
@@ -26,28 +29,49 @@
 #![allow(clippy::multiple_inherent_impl)]
 #![allow(clippy::multiple_crate_versions)]
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::string::FromUtf8Error;
+use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
+mod arithmetic;
+mod bitwise;
+#[cfg(feature = "gc")]
+mod branches;
+mod capacity;
 mod clone;
+mod collect;
 mod ctors;
 mod debug;
 mod dot;
+mod edges;
+mod encoding;
+mod error;
 mod hex;
 mod inspect;
 mod label;
+#[cfg(feature = "gc")]
+mod members;
 mod merge;
 mod misc;
 mod next;
+mod numeric;
+mod observe;
 mod ops;
+mod parents;
+mod remove;
 mod script;
 mod serialization;
 mod slice;
+mod transaction;
+mod weak;
 mod xml;
 
 const HEX_SIZE: usize = 8;
-const MAX_BRANCHES: usize = 16;
+#[cfg(feature = "gc")]
+const INITIAL_BRANCHES: usize = 16;
+#[cfg(feature = "gc")]
 const MAX_BRANCH_SIZE: usize = 16;
 
 /// An object-oriented representation of binary data
@@ -68,10 +92,54 @@ const MAX_BRANCH_SIZE: usize = 16;
 /// let d = Hex::from(65534_i64);
 /// assert_eq!(65534, d.to_i64().unwrap());
 /// ```
+///
+/// Large data may be kept in a shared buffer, see [`Hex::from_arc`],
+/// which is not copied when the [`Hex`] is cloned.
 #[derive(Serialize, Deserialize, Clone)]
 pub enum Hex {
     Vector(Vec<u8>),
     Bytes([u8; HEX_SIZE], usize),
+    /// A part of an immutable buffer, from the first position to the second one.
+    #[serde(
+        serialize_with = "Hex::save_shared",
+        deserialize_with = "Hex::load_shared"
+    )]
+    Shared(Arc<[u8]>, usize, usize),
+}
+
+/// A number, which can be put into a [`Hex`] and taken back, in either byte order.
+///
+/// It is implemented for all primitive integers and floats, and for `char`,
+/// see [`Hex::from_le`] and [`Hex::to_le`], for example:
+///
+/// ```
+/// use sodg::Hex;
+/// let d = Hex::from_le(0x1234_u16);
+/// assert_eq!("34-12", d.print());
+/// assert_eq!(0x1234, d.to_le::<u16>().unwrap());
+/// assert_eq!(0x3412, u16::try_from(&d).unwrap());
+/// ```
+pub trait Numeric: Sized {
+    /// The name of the type, as reported by [`SodgError::HexLength`].
+    const KIND: &'static str;
+    /// Turn it into big-endian bytes.
+    fn to_be_hex(self) -> Hex;
+    /// Turn it into little-endian bytes.
+    fn to_le_hex(self) -> Hex;
+    /// Make it from big-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the number of bytes is wrong or they don't make a valid value,
+    /// an `Err` will be returned.
+    fn from_be_slice(bytes: &[u8]) -> Result<Self, SodgError>;
+    /// Make it from little-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the number of bytes is wrong or they don't make a valid value,
+    /// an `Err` will be returned.
+    fn from_le_slice(bytes: &[u8]) -> Result<Self, SodgError>;
 }
 
 /// A label on an edge.
@@ -82,6 +150,86 @@ pub enum Label {
     Str([char; 8]),
 }
 
+/// An error that may happen while working with a [`Sodg`], a [`Hex`],
+/// a [`Label`], or a [`Script`].
+///
+/// You can react to each of them programmatically, for example:
+///
+/// ```
+/// use sodg::{Label, Sodg, SodgError};
+/// let mut g : Sodg<16> = Sodg::empty(256);
+/// g.add(0);
+/// match g.try_bind(0, 1, Label::Alpha(0)) {
+///     Err(SodgError::VertexAbsent(v)) => assert_eq!(1, v),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SodgError {
+    /// The vertex is not in the graph.
+    VertexAbsent(usize),
+    /// The ID of the vertex is too big, the graph can't grow that far.
+    IdTooBig(usize),
+    /// An edge from the vertex to itself was requested.
+    SelfLoop { v: usize, label: Label },
+    /// All branches are busy, see `Sodg::limit_branches`, a new one can't be started.
+    BranchesFull,
+    /// The vertex can't be removed, because these edges point to it.
+    Dangling {
+        v: usize,
+        edges: Vec<(usize, Label)>,
+    },
+    /// The graph being merged is not a tree, these vertices were not merged.
+    NotATree { missed: Vec<usize> },
+    /// The text of a label is longer than eight characters.
+    LabelTooLong(String),
+    /// The text can't be parsed as a label.
+    BadLabel(String),
+    /// The text can't be parsed as a hexadecimal literal.
+    BadHexLiteral(String),
+    /// The text can't be parsed as a Base64 literal.
+    BadBase64Literal(String),
+    /// The number of bytes in a [`Hex`] doesn't fit the type requested.
+    HexLength {
+        kind: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// The result of an arithmetic operation on two integers doesn't fit
+    /// into `i64`, the result of an operation on two floats is not finite,
+    /// or the divisor is zero.
+    Arithmetic {
+        op: &'static str,
+        left: String,
+        right: String,
+    },
+    /// The number is not a valid Unicode code point, so it can't be a `char`.
+    BadChar(u32),
+    /// The number doesn't fit into `usize` or `isize` of this platform.
+    TooBig { kind: &'static str, value: i128 },
+    /// The bytes are not a valid UTF-8 string.
+    NotUtf8(FromUtf8Error),
+    /// The command of a [`Script`] can't be parsed,
+    /// its position is counted from zero.
+    ScriptSyntax { command: String, position: usize },
+    /// The command of a [`Script`] was parsed, but failed,
+    /// its position is counted from zero.
+    Script {
+        command: String,
+        position: usize,
+        source: Box<Self>,
+    },
+    /// The file system failed.
+    Io(std::io::Error),
+    /// The graph can't be serialized.
+    Encode(bincode::error::EncodeError),
+    /// The graph can't be deserialized.
+    Decode(bincode::error::DecodeError),
+    /// The XML builder failed.
+    Xml(xml_builder::XMLError),
+}
+
 /// A wrapper of a plain text with graph-modifying instructions.
 ///
 /// For example, you can pass the following instructions to it:
@@ -91,6 +239,8 @@ pub enum Label {
 /// ADD($ν1); # adding new vertex
 /// BIND(0, $ν1, foo);
 /// PUT($ν1, d0-bf-D1-80-d0-B8-d0-b2-d0-b5-d1-82);
+/// UNBIND(0, foo);
+/// DEL($ν1);
 /// ```
 ///
 /// In the script you can use "variables", similar to `$ν1` used
@@ -124,21 +274,165 @@ pub struct Script {
 /// project, as a memory model for objects and dependencies between them.
 #[derive(Serialize, Deserialize)]
 pub struct Sodg<const N: usize> {
-    stores: emap::Map<usize>,
-    branches: emap::Map<microstack::Stack<usize, MAX_BRANCH_SIZE>>,
+    #[cfg(feature = "gc")]
+    stores: Vec<usize>,
+    #[cfg(feature = "gc")]
+    branches: Vec<Members>,
+    /// These are the branches destroyed as garbage, to be taken by [`Sodg::bind`] first.
+    #[cfg(feature = "gc")]
+    idle: Vec<usize>,
+    /// This is the maximum number of branches, see [`Sodg::limit_branches`].
+    #[cfg(feature = "gc")]
+    max_branches: usize,
     vertices: emap::Map<Vertex<N>>,
-    /// This is the next ID of a vertex to be returned by the [`Sodg::next_v`] function.
-    #[serde(skip_serializing, skip_deserializing)]
+    /// This is the number of vertices alive, see [`Sodg::len`].
+    alive: usize,
+    /// This is the number of edges departing from vertices alive, see [`Sodg::edge_count`].
+    arrows: usize,
+    /// This is the next ID of a vertex to be returned by the [`Sodg::next_id`] function,
+    /// if there are no `freed` or `skipped` ones.
     next_v: usize,
+    /// Whether the parents of each vertex are indexed, see [`Sodg::index_parents`].
+    indexed: bool,
+    /// These are the IDs of removed vertices, to be returned by [`Sodg::next_id`] first.
+    freed: Vec<usize>,
+    /// These are the ranges of IDs jumped over by [`Sodg::add`], from the start
+    /// to the end, exclusive, to be returned by [`Sodg::next_id`] after the `freed` ones.
+    skipped: BTreeMap<usize, usize>,
+    /// These are the observers registered by [`Sodg::observe`], they are not saved.
+    #[serde(skip)]
+    observers: Vec<Box<dyn Observer>>,
+    /// These are the vertices kept alive by [`Sodg::collect`], see [`Sodg::set_root`].
+    roots: BTreeSet<usize>,
+    /// This is the way garbage is found, see [`Sodg::set_collector`].
+    collector: Collector,
+    /// These are the labels of weak edges, see [`Sodg::weaken`].
+    weak: BTreeSet<Label>,
+    /// This is the state before the transaction, see [`Sodg::begin`].
+    #[serde(skip)]
+    journal: Option<Box<Journal<N>>>,
+}
+
+/// The way a [`Sodg`] finds its garbage, see [`Sodg::set_collector`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Collector {
+    /// A branch of vertices is destroyed as soon as all its data is read
+    /// by [`Sodg::data`], if the `gc` feature is enabled.
+    Surging,
+    /// Nothing is destroyed until [`Sodg::collect`] is called, which destroys
+    /// all vertices not reachable from the roots, see [`Sodg::set_root`].
+    Tracing,
+}
+
+/// An observer of the changes in a [`Sodg`], registered by [`Sodg::observe`].
+///
+/// All methods do nothing by default, so you implement only the ones you need,
+/// for example, in order to release native resources tied to vertices:
+///
+/// ```
+/// use sodg::{Observer, Sodg};
+/// struct Janitor;
+/// impl Observer for Janitor {
+///     fn on_collect(&mut self, vs: &[usize]) {
+///         println!("{} vertices destroyed as garbage", vs.len());
+///     }
+/// }
+/// let mut g : Sodg<16> = Sodg::empty(256);
+/// g.observe(Janitor);
+/// ```
+pub trait Observer {
+    /// The vertices `vs` were destroyed as garbage.
+    fn on_collect(&mut self, _vs: &[usize]) {}
+    /// The data `d` was put into vertex `v` by [`Sodg::put`].
+    fn on_put(&mut self, _v: usize, _d: &Hex) {}
+    /// The edge from `v1` to `v2` with label `a` was made by [`Sodg::bind`].
+    fn on_bind(&mut self, _v1: usize, _v2: usize, _a: Label) {}
 }
 
-#[derive(PartialEq, Serialize, Deserialize, Clone)]
-enum Persistence {
+/// What to do with the edges pointing to a vertex, which is being removed
+/// by [`Sodg::remove`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Dangling {
+    /// Remove them together with the vertex.
+    Unbind,
+    /// Refuse to remove the vertex and report them as [`SodgError::Dangling`].
+    Report,
+}
+
+/// The state of the data in a vertex, see [`Sodg::persistence`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Persistence {
+    /// No data was ever put into the vertex.
     Empty,
+    /// The data was put, but not yet read by [`Sodg::data`].
     Stored,
+    /// The data was read by [`Sodg::data`] at least once.
     Taken,
 }
 
+/// The state of a vertex in a graph, see [`Sodg::liveness`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Liveness {
+    /// The vertex was never added, or it was removed by [`Sodg::remove`].
+    Absent,
+    /// The vertex is in the graph.
+    Alive,
+    /// The vertex was destroyed as garbage.
+    Collected,
+}
+
+/// The vertices of a branch.
+///
+/// Small branches keep their members inline, without touching the heap.
+/// When a branch grows beyond [`MAX_BRANCH_SIZE`] members, they
+/// are moved to the heap, where there is no limit.
+#[cfg(feature = "gc")]
+#[derive(Clone, Serialize, Deserialize)]
+enum Members {
+    Inline(microstack::Stack<usize, MAX_BRANCH_SIZE>),
+    Spilled(Vec<usize>),
+}
+
+/// The edges departing from a vertex.
+///
+/// Up to `N` edges are kept inline, without touching the heap.
+/// When there are more of them, they are moved to the heap,
+/// where there is no limit.
+#[derive(Clone, Serialize, Deserialize)]
+enum Edges<const N: usize> {
+    Inline(micromap::Map<Label, usize, N>),
+    Spilled(BTreeMap<Label, usize>),
+}
+
+/// The state of a [`Sodg`] before a transaction, see [`Sodg::begin`].
+///
+/// Vertices and branches are saved only when they are about to be changed
+/// for the first time in the transaction.
+struct Journal<const N: usize> {
+    /// The vertices changed, as they were before the transaction.
+    vertices: HashMap<usize, Option<Vertex<N>>>,
+    /// The members and the stores of the branches changed,
+    /// as they were before the transaction.
+    #[cfg(feature = "gc")]
+    branches: HashMap<usize, (Members, usize)>,
+    /// The number of branches before the transaction.
+    #[cfg(feature = "gc")]
+    total: usize,
+    #[cfg(feature = "gc")]
+    idle: Vec<usize>,
+    #[cfg(feature = "gc")]
+    max_branches: usize,
+    alive: usize,
+    arrows: usize,
+    next_v: usize,
+    indexed: bool,
+    freed: Vec<usize>,
+    skipped: BTreeMap<usize, usize>,
+    roots: BTreeSet<usize>,
+    collector: Collector,
+    weak: BTreeSet<Label>,
+}
+
 const BRANCH_NONE: usize = 0;
 const BRANCH_STATIC: usize = 1;
 
@@ -147,7 +441,15 @@ struct Vertex<const N: usize> {
     branch: usize,
     data: Hex,
     persistence: Persistence,
-    edges: micromap::Map<Label, usize, N>,
+    edges: Edges<N>,
+    /// The edges pointing to this vertex, if [`Sodg::index_parents`] is enabled.
+    parents: Vec<(usize, Label)>,
+    /// Whether the vertex was destroyed as garbage, see [`Sodg::liveness`].
+    collected: bool,
+    /// The weak edges pointing to this vertex, see [`Sodg::weaken`].
+    weak: Vec<(usize, Label)>,
+    /// Whether the ID is in the list of freed ones, see [`Sodg::next_id`].
+    listed: bool,
 }
 
 #[cfg(test)]
@@ -0,0 +1,86 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use itertools::Either;
+
+use crate::{MAX_BRANCH_SIZE, Members};
+
+impl Members {
+    /// Make an empty list of members.
+    pub(crate) const fn new() -> Self {
+        Self::Inline(microstack::Stack::new())
+    }
+
+    /// Make a list of members from a vector.
+    pub(crate) fn from_vec(vs: Vec<usize>) -> Self {
+        if vs.len() > MAX_BRANCH_SIZE {
+            Self::Spilled(vs)
+        } else {
+            Self::Inline(microstack::Stack::from_vec(vs))
+        }
+    }
+
+    /// Add one more member, moving all of them to the heap, if necessary.
+    pub(crate) fn push(&mut self, v: usize) {
+        match self {
+            Self::Inline(stack) if stack.len() < MAX_BRANCH_SIZE => stack.push(v),
+            Self::Inline(stack) => {
+                let mut vs = Vec::with_capacity(MAX_BRANCH_SIZE * 2);
+                vs.extend(stack.into_iter());
+                vs.push(v);
+                *self = Self::Spilled(vs);
+            }
+            Self::Spilled(vs) => vs.push(v),
+        }
+    }
+
+    /// How many members are there?
+    pub(crate) const fn len(&self) -> usize {
+        match self {
+            Self::Inline(stack) => stack.len(),
+            Self::Spilled(vs) => vs.len(),
+        }
+    }
+
+    /// Is it empty?
+    pub(crate) const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate the members.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        match self {
+            Self::Inline(stack) => Either::Left(stack.into_iter()),
+            Self::Spilled(vs) => Either::Right(vs.iter().copied()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_small_branch_inline() {
+        let mut m = Members::new();
+        for v in 0..MAX_BRANCH_SIZE {
+            m.push(v);
+        }
+        assert!(matches!(m, Members::Inline(_)));
+        assert_eq!(MAX_BRANCH_SIZE, m.len());
+    }
+
+    #[test]
+    fn spills_to_heap() {
+        let mut m = Members::new();
+        for v in 0..100 {
+            m.push(v);
+        }
+        assert!(matches!(m, Members::Spilled(_)));
+        assert_eq!(
+            (0..100).collect::<Vec<usize>>(),
+            m.iter().collect::<Vec<usize>>()
+        );
+        assert!(matches!(Members::from_vec(vec![1, 2]), Members::Inline(_)));
+    }
+}
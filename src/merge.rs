@@ -1,12 +1,11 @@
 // SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
 // SPDX-License-Identifier: MIT
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
-use anyhow::{Result, bail};
 use log::debug;
 
-use crate::{Label, Persistence, Sodg};
+use crate::{Label, Persistence, Sodg, SodgError, Vertex};
 
 impl<const N: usize> Sodg<N> {
     /// Merge another graph into the current one.
@@ -22,31 +21,24 @@ impl<const N: usize> Sodg<N> {
     ///
     /// # Errors
     ///
-    /// If it's impossible to merge, an error will be returned.
-    pub fn merge(&mut self, g: &Self, left: usize, right: usize) -> Result<()> {
+    /// If it's impossible to merge, an error will be returned. If the right
+    /// graph is not a tree, [`SodgError::NotATree`] will be returned with
+    /// the list of vertices that were not merged.
+    pub fn merge(&mut self, g: &Self, left: usize, right: usize) -> Result<(), SodgError> {
         let mut mapped = HashMap::new();
         let before = self.len();
         self.merge_rec(g, left, right, &mut mapped)?;
         let merged = mapped.len();
         let scope = g.len();
         if merged != scope {
-            let must = g.keys();
-            let seen = mapped.keys().copied().collect::<Vec<usize>>();
-            let missed: HashSet<usize> =
-                &HashSet::from_iter(must.clone()) - &HashSet::from_iter(seen.clone());
-            let mut ordered: Vec<usize> = missed.into_iter().collect();
+            let mut ordered: Vec<usize> =
+                g.vertices().filter(|v| !mapped.contains_key(v)).collect();
             ordered.sort_unstable();
-            bail!(
-                "Just {merged} vertices merged, out of {scope} (must={}, seen={}); maybe the right graph was not a tree? {} missed: {}",
-                must.len(),
-                seen.len(),
+            debug!(
+                "Just {merged} vertices merged, out of {scope} ({} missed)",
                 ordered.len(),
-                ordered
-                    .iter()
-                    .map(|v| format!("ν{v}"))
-                    .collect::<Vec<String>>()
-                    .join(", "),
             );
+            return Err(SodgError::NotATree { missed: ordered });
         }
         debug!(
             "Merged all {merged} vertices into SODG of {}, making it have {} after the merge",
@@ -74,49 +66,44 @@ impl<const N: usize> Sodg<N> {
         left: usize,
         right: usize,
         mapped: &mut HashMap<usize, usize>,
-    ) -> Result<()> {
+    ) -> Result<(), SodgError> {
         if mapped.contains_key(&right) {
             return Ok(());
         }
         mapped.insert(right, left);
-        if g.vertices.get(right).unwrap().persistence != Persistence::Empty {
-            self.put(left, &g.vertices.get(right).unwrap().data);
+        let vtx = g.vertex(right)?;
+        if vtx.persistence != Persistence::Empty {
+            self.try_put(left, &vtx.data)?;
         }
         for (a, to) in g.kids(right) {
             let matched = if let Some(t) = self.kid(left, *a) {
                 t
             } else if let Some(t) = mapped.get(to) {
-                self.bind(left, *t, *a);
+                self.try_bind(left, *t, *a)?;
                 *t
             } else {
                 let id = self.next_id();
-                self.add(id);
-                self.bind(left, id, *a);
+                self.try_add(id)?;
+                self.try_bind(left, id, *a)?;
                 id
             };
             self.merge_rec(g, matched, *to, mapped)?;
         }
         for (a, to) in g.kids(right) {
-            if let Some(first) = self.kid(left, *a) {
-                if let Some(second) = mapped.get(to) {
-                    if first != *second {
-                        self.join(first, *second);
-                    }
-                }
+            if let Some(first) = self.kid(left, *a)
+                && let Some(second) = mapped.get(to)
+                && first != *second
+            {
+                self.join(first, *second)?;
             }
         }
         Ok(())
     }
 
-    fn join(&mut self, left: usize, right: usize) {
-        for v in self.keys() {
-            let mut nv = self.vertices.get(v).unwrap().clone();
-            for e in &self.vertices.get_mut(v).unwrap().edges {
-                if *e.1 == right {
-                    nv.edges.insert(*e.0, left);
-                }
-            }
-            self.vertices.insert(v, nv);
+    fn join(&mut self, left: usize, right: usize) -> Result<(), SodgError> {
+        let parents = self.parents(right).collect::<Vec<(usize, Label)>>();
+        for (v, a) in &parents {
+            self.try_bind(*v, left, *a)?;
         }
         let kids = self
             .kids(right)
@@ -128,9 +115,21 @@ impl<const N: usize> Sodg<N> {
                 "Can't merge ν{right} into ν{left}, due to conflict in '{}'",
                 e.0,
             );
-            self.bind(left, e.1, e.0);
+            self.try_bind(left, e.1, e.0)?;
+            self.unlink(right, e.0, e.1);
         }
-        self.vertices.remove(right);
+        let before = self.vertices[right].clone();
+        #[cfg(feature = "gc")]
+        self.leave(right, &before, &parents)?;
+        self.arrows -= before.edges.len();
+        self.touch(right);
+        let mut empty = Vertex::empty();
+        empty.listed = before.listed;
+        self.vertices.insert(right, empty);
+        self.roots.remove(&right);
+        self.alive -= 1;
+        self.free(right);
+        Ok(())
     }
 }
 
@@ -166,8 +165,10 @@ mod tests {
         extra.add(13);
         let r = g.merge(&extra, 0, 0);
         assert!(r.is_err());
-        let msg = r.err().unwrap().to_string();
+        let e = r.err().unwrap();
+        let msg = e.to_string();
         assert!(msg.contains("ν2, ν13, ν42"), "{}", msg);
+        assert!(matches!(e, SodgError::NotATree { missed } if missed == vec![2, 13, 42]));
     }
 
     #[test]
@@ -196,6 +197,67 @@ mod tests {
         // assert_eq!(5, g.kid(1, "e").unwrap());
     }
 
+    #[test]
+    fn forgets_joined_vertex() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::from_str("a").unwrap());
+        g.add(2);
+        g.bind(1, 2, Label::from_str("b").unwrap());
+        g.put(2, &Hex::from(42_i64));
+        let mut extra = Sodg::empty(256);
+        extra.add(0);
+        extra.add(4);
+        extra.bind(0, 4, Label::from_str("c").unwrap());
+        extra.add(3);
+        extra.bind(0, 3, Label::from_str("a").unwrap());
+        extra.bind(4, 3, Label::from_str("d").unwrap());
+        extra.add(5);
+        extra.bind(3, 5, Label::from_str("e").unwrap());
+        g.merge(&extra, 0, 0).unwrap();
+        assert!(!g.contains(4));
+        assert!(!format!("{g:?}").contains("ν4"));
+        assert_eq!(4, g.next_id());
+        assert_eq!(6, g.next_id());
+        assert_eq!(42, g.data(2).unwrap().to_i64().unwrap());
+    }
+
+    #[test]
+    fn redirects_parents_of_joined_vertex() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.index_parents();
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::from_str("a").unwrap());
+        g.add(2);
+        g.bind(1, 2, Label::from_str("b").unwrap());
+        g.put(2, &Hex::from(42_i64));
+        let mut extra = Sodg::empty(256);
+        extra.add(0);
+        extra.add(4);
+        extra.bind(0, 4, Label::from_str("c").unwrap());
+        extra.add(3);
+        extra.bind(0, 3, Label::from_str("a").unwrap());
+        extra.bind(4, 3, Label::from_str("d").unwrap());
+        extra.add(5);
+        extra.bind(3, 5, Label::from_str("e").unwrap());
+        g.merge(&extra, 0, 0).unwrap();
+        assert!(!g.contains(4));
+        assert_eq!(Some(1), g.kid(3, Label::from_str("d").unwrap()));
+        assert_eq!(2, g.in_degree(1));
+        assert_eq!(1, g.in_degree(3));
+        g.set_root(0);
+        g.unbind(0, Label::from_str("a").unwrap());
+        assert!(g.collect().is_empty());
+        g.unbind(0, Label::from_str("c").unwrap());
+        let mut gone = g.collect();
+        gone.sort_unstable();
+        assert_eq!(vec![1, 2, 3, 5], gone);
+        assert_eq!(vec![0], g.keys());
+        assert_eq!(0, g.edge_count());
+    }
+
     #[test]
     fn avoids_simple_duplicates() {
         let mut g: Sodg<16> = Sodg::empty(256);
@@ -1,29 +1,162 @@
 // SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
 // SPDX-License-Identifier: MIT
 
-use crate::Sodg;
+use crate::{BRANCH_NONE, Label, Liveness, Sodg};
 
 impl<const N: usize> Sodg<N> {
     /// Get total number of vertices in the graph.
+    ///
+    /// It works in constant time, since the number is maintained
+    /// while vertices are added, removed, and destroyed as garbage.
     #[must_use]
-    pub fn len(&self) -> usize {
-        self.keys().len()
+    pub const fn len(&self) -> usize {
+        self.alive
     }
 
     /// Is it empty?
     #[must_use]
-    pub fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
+    /// Set the maximum number of branches the graph may have at the same time,
+    /// not counting the one for vertices without edges.
+    ///
+    /// By default, there is no limit and the table of branches grows on demand.
+    /// When the limit is reached, [`Sodg::try_bind`] returns
+    /// [`crate::SodgError::BranchesFull`], until some branches are destroyed
+    /// as garbage. Without the `gc` feature, there are no branches and no such method.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.limit_branches(3);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// ```
+    #[cfg(feature = "gc")]
+    pub const fn limit_branches(&mut self, max: usize) {
+        self.max_branches = max;
+    }
+
+    /// Is vertex `v` in the graph?
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Sodg;
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(42);
+    /// assert!(g.contains(42));
+    /// assert!(!g.contains(7));
+    /// assert!(!g.contains(1000));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, v: usize) -> bool {
+        self.vertex(v).is_ok()
+    }
+
+    /// Find out whether vertex `v` is in the graph, was never added to it,
+    /// or was destroyed as garbage.
+    ///
+    /// A vertex removed by [`Sodg::remove`] is [`Liveness::Absent`], as if it
+    /// was never added. A vertex destroyed as garbage is [`Liveness::Collected`],
+    /// until it is added again by [`Sodg::add`]. For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Label, Liveness, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.put(1, &Hex::from(42));
+    /// assert_eq!(Liveness::Alive, g.liveness(1));
+    /// assert_eq!(Liveness::Absent, g.liveness(2));
+    /// g.data(1);
+    /// # #[cfg(feature = "gc")]
+    /// assert_eq!(Liveness::Collected, g.liveness(1));
+    /// ```
+    #[must_use]
+    pub fn liveness(&self, v: usize) -> Liveness {
+        if v >= self.vertices.capacity() {
+            return Liveness::Absent;
+        }
+        match self.vertices.get(v) {
+            Some(vtx) if vtx.branch != BRANCH_NONE => Liveness::Alive,
+            Some(vtx) if vtx.collected => Liveness::Collected,
+            _ => Liveness::Absent,
+        }
+    }
+
     /// Get keys of all vertices alive?
+    ///
+    /// If you don't need a vector, use [`Sodg::vertices`], which allocates nothing.
     #[must_use]
     pub fn keys(&self) -> Vec<usize> {
+        self.vertices().collect::<Vec<usize>>()
+    }
+
+    /// Iterate all edges departing from vertices alive, as triples
+    /// of the vertex, the label, and the vertex the edge is pointing to.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(42);
+    /// g.bind(0, 42, Label::Alpha(0));
+    /// assert_eq!(vec![(0, Label::Alpha(0), 42)], g.edges().collect::<Vec<_>>());
+    /// ```
+    ///
+    /// The edges are ordered by the vertices they depart from.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, Label, usize)> + '_ {
+        self.vertices().flat_map(move |v| {
+            self.vertices[v]
+                .edges
+                .iter()
+                .map(move |(a, to)| (v, *a, *to))
+        })
+    }
+
+    /// Get total number of edges departing from vertices alive.
+    ///
+    /// It works in constant time, for example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(42);
+    /// g.bind(0, 42, Label::Alpha(0));
+    /// g.bind(0, 42, Label::Alpha(1));
+    /// assert_eq!(2, g.edge_count());
+    /// ```
+    #[must_use]
+    pub const fn edge_count(&self) -> usize {
+        self.arrows
+    }
+
+    /// Iterate the IDs of all vertices alive.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Sodg;
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(42);
+    /// assert_eq!(vec![0, 42], g.vertices().collect::<Vec<usize>>());
+    /// ```
+    pub fn vertices(&self) -> impl Iterator<Item = usize> + '_ {
         self.vertices
             .iter()
-            .filter(|(_, vtx)| vtx.branch != 0)
+            .filter(|(_, vtx)| vtx.branch != BRANCH_NONE)
             .map(|(v, _)| v)
-            .collect::<Vec<usize>>()
     }
 }
 
@@ -36,4 +169,76 @@ mod tests {
         let g: Sodg<16> = Sodg::empty(256);
         assert_eq!(0, g.len());
     }
+
+    #[test]
+    fn keeps_counter_in_sync() {
+        let mut g: Sodg<16> = Sodg::empty(4);
+        for v in 0..10 {
+            g.add(v);
+            g.add(v);
+        }
+        assert_eq!(10, g.len());
+        g.bind(0, 1, crate::Label::Alpha(0));
+        g.bind(1, 2, crate::Label::Alpha(0));
+        g.put(2, &crate::Hex::from(42));
+        g.remove(5, crate::Dangling::Report);
+        g.data(2);
+        g.add(1);
+        assert_eq!(g.vertices().count(), g.len());
+        assert_eq!(g.keys(), g.vertices().collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn checks_liveness_of_vertices() {
+        let mut g: Sodg<16> = Sodg::empty(4);
+        assert_eq!(Liveness::Absent, g.liveness(0));
+        assert_eq!(Liveness::Absent, g.liveness(100));
+        g.add(0);
+        g.add(1);
+        assert!(g.contains(1));
+        g.remove(1, crate::Dangling::Report);
+        assert!(!g.contains(1));
+        assert_eq!(Liveness::Absent, g.liveness(1));
+        assert_eq!(Liveness::Alive, g.liveness(0));
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn tells_collected_vertex_apart() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.add(0);
+        g.add(1);
+        g.add(2);
+        g.bind(0, 1, crate::Label::Alpha(0));
+        g.bind(1, 2, crate::Label::Alpha(0));
+        g.put(2, &crate::Hex::from(42));
+        g.data(2);
+        for v in 0..3 {
+            assert!(!g.contains(v));
+            assert_eq!(Liveness::Collected, g.liveness(v));
+        }
+        g.add(1);
+        assert_eq!(Liveness::Alive, g.liveness(1));
+        assert_eq!(Liveness::Collected, g.liveness(2));
+    }
+
+    #[test]
+    fn keeps_edge_count_in_sync() {
+        let mut g: Sodg<2> = Sodg::empty(16);
+        for v in 0..8 {
+            g.add(v);
+        }
+        for v in 1..5 {
+            g.bind(0, v, crate::Label::Alpha(v));
+        }
+        g.bind(0, 4, crate::Label::Alpha(4));
+        g.bind(5, 6, crate::Label::Alpha(0));
+        g.bind(6, 7, crate::Label::Alpha(0));
+        assert_eq!(6, g.edge_count());
+        g.unbind(0, crate::Label::Alpha(1));
+        g.remove(6, crate::Dangling::Unbind);
+        g.put(3, &crate::Hex::from(42));
+        g.data(3);
+        assert_eq!(g.edges().count(), g.edge_count());
+    }
 }
@@ -1,32 +1,82 @@
 // SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
 // SPDX-License-Identifier: MIT
 
-use crate::Sodg;
+use crate::{Sodg, Vertex};
 
 impl<const N: usize> Sodg<N> {
     /// Get next unique ID of a vertex.
     ///
-    /// This ID will never be returned by [`Sodg::next_id`] again. Also, this ID will not
+    /// This ID will never be returned by [`Sodg::next_id`] again, unless the vertex
+    /// is removed by [`Sodg::remove`] or destroyed as garbage. Also, this ID will not
     /// be equal to any of the existing IDs of vertices.
     ///
-    /// # Panics
-    ///
-    /// May panic if not enough IDs are available.
+    /// The ID may be beyond the capacity of the graph, which will
+    /// grow when the vertex is added by [`Sodg::add`].
+    /// The IDs of vertices removed or destroyed as garbage are taken first,
+    /// then the IDs jumped over by [`Sodg::add`], that's why it works in constant
+    /// time. The state of this allocation
+    /// is saved by [`Sodg::save`] and restored by [`Sodg::load`].
     #[inline]
     pub fn next_id(&mut self) -> usize {
-        let mut id = self.next_v;
-        id = self
-            .vertices
-            .iter()
-            .find(|(v, vtx)| vtx.branch == 0 && *v >= id)
-            .map(|(v, _)| v)
-            .unwrap();
-        let next = id + 1;
-        if next > self.next_v {
-            self.next_v = next;
+        while let Some(id) = self.freed.pop() {
+            self.touch(id);
+            if let Some(vtx) = self.vertices.get_mut(id) {
+                vtx.listed = false;
+            }
+            if self.vertex(id).is_err() {
+                return id;
+            }
+        }
+        if let Some((start, end)) = self.skipped.pop_first() {
+            if start + 1 < end {
+                self.skipped.insert(start + 1, end);
+            }
+            return start;
         }
+        let id = self.next_v;
+        self.next_v += 1;
         id
     }
+
+    /// Make sure the ID `v` of a vertex just added will not be returned
+    /// by [`Sodg::next_id`], while the IDs below it, never returned yet, will be.
+    ///
+    /// The IDs jumped over are kept as a range, no matter how many of them there are.
+    pub(crate) fn occupy(&mut self, v: usize) {
+        if v >= self.next_v {
+            if v > self.next_v {
+                self.skipped.insert(self.next_v, v);
+            }
+            self.next_v = v + 1;
+        } else if let Some((&start, &end)) = self.skipped.range(..=v).next_back()
+            && v < end
+        {
+            self.skipped.remove(&start);
+            if start < v {
+                self.skipped.insert(start, v);
+            }
+            if v + 1 < end {
+                self.skipped.insert(v + 1, end);
+            }
+        }
+    }
+
+    /// Put the ID `v` to the list of IDs to be returned by [`Sodg::next_id`],
+    /// unless it's already there.
+    pub(crate) fn free(&mut self, v: usize) {
+        self.touch(v);
+        if let Some(vtx) = self.vertices.get_mut(v) {
+            if vtx.listed {
+                return;
+            }
+            vtx.listed = true;
+        } else {
+            let mut vtx = Vertex::empty();
+            vtx.listed = true;
+            self.vertices.insert(v, vtx);
+        }
+        self.freed.push(v);
+    }
 }
 
 #[cfg(test)]
@@ -67,6 +117,82 @@ mod tests {
         assert_eq!(3, g.next_id());
     }
 
+    #[test]
+    fn reuses_id_of_garbage() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, crate::Label::Alpha(0));
+        g.put(1, &crate::Hex::from(42));
+        assert_eq!(2, g.next_id());
+        g.data(1);
+        #[cfg(feature = "gc")]
+        {
+            let mut ids = vec![g.next_id(), g.next_id()];
+            ids.sort_unstable();
+            assert_eq!(vec![0, 1], ids);
+            g.add(0);
+            assert_eq!(0, g.kids(0).count());
+        }
+        assert_eq!(3, g.next_id());
+    }
+
+    #[test]
+    fn never_gives_same_id_twice() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.remove(1, crate::Dangling::Unbind);
+        g.add(1);
+        g.remove(1, crate::Dangling::Unbind);
+        assert_eq!(1, g.next_id());
+        assert_eq!(2, g.next_id());
+    }
+
+    #[test]
+    fn skips_id_taken_by_add() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.remove(1, crate::Dangling::Unbind);
+        g.add(1);
+        assert_eq!(2, g.next_id());
+        g.remove(1, crate::Dangling::Unbind);
+        assert_eq!(1, g.next_id());
+        assert_eq!(3, g.next_id());
+    }
+
+    #[test]
+    fn jumps_far_in_constant_time() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.add(80_000);
+        g.add(5);
+        assert_eq!(2, g.len());
+        assert_eq!(
+            vec![(0, 5), (6, 80_000)],
+            g.skipped.clone().into_iter().collect::<Vec<_>>()
+        );
+        for v in 0..5 {
+            assert_eq!(v, g.next_id());
+        }
+        assert_eq!(6, g.next_id());
+        g.add(8);
+        assert_eq!(7, g.next_id());
+        assert_eq!(9, g.next_id());
+    }
+
+    #[test]
+    fn never_gives_jumped_id_twice() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.add(10);
+        g.add(3);
+        g.remove(3, crate::Dangling::Unbind);
+        let mut ids: Vec<usize> = (0..10).map(|_| g.next_id()).collect();
+        ids.sort_unstable();
+        assert_eq!((0..10).collect::<Vec<_>>(), ids);
+        assert_eq!(11, g.next_id());
+    }
+
     #[test]
     fn next_id_after_zero() {
         let mut g: Sodg<16> = Sodg::empty(256);
@@ -0,0 +1,313 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use crate::{Hex, Numeric, SodgError};
+
+/// Take exactly `S` bytes, or explain why it's impossible to make `kind` of them.
+fn exact<const S: usize>(kind: &'static str, bytes: &[u8]) -> Result<[u8; S], SodgError> {
+    bytes.try_into().map_err(|_| SodgError::HexLength {
+        kind,
+        expected: S,
+        actual: bytes.len(),
+    })
+}
+
+macro_rules! numeric {
+    ($t:ty, $kind:literal) => {
+        impl Numeric for $t {
+            const KIND: &'static str = $kind;
+
+            fn to_be_hex(self) -> Hex {
+                Hex::from_slice(&self.to_be_bytes())
+            }
+
+            fn to_le_hex(self) -> Hex {
+                Hex::from_slice(&self.to_le_bytes())
+            }
+
+            fn from_be_slice(bytes: &[u8]) -> Result<Self, SodgError> {
+                Ok(Self::from_be_bytes(exact(Self::KIND, bytes)?))
+            }
+
+            fn from_le_slice(bytes: &[u8]) -> Result<Self, SodgError> {
+                Ok(Self::from_le_bytes(exact(Self::KIND, bytes)?))
+            }
+        }
+
+        impl TryFrom<&Hex> for $t {
+            type Error = SodgError;
+
+            /// Take the number back from big-endian bytes of a [`Hex`].
+            fn try_from(h: &Hex) -> Result<Self, Self::Error> {
+                h.to_be()
+            }
+        }
+    };
+}
+
+/// Make a number of the platform-dependent size always take
+/// as many bytes as the `wide` type, so that it can be read on any platform.
+macro_rules! numeric_wide {
+    ($t:ty, $wide:ty, $kind:literal) => {
+        impl Numeric for $t {
+            const KIND: &'static str = $kind;
+
+            fn to_be_hex(self) -> Hex {
+                (self as $wide).to_be_hex()
+            }
+
+            fn to_le_hex(self) -> Hex {
+                (self as $wide).to_le_hex()
+            }
+
+            fn from_be_slice(bytes: &[u8]) -> Result<Self, SodgError> {
+                let w = <$wide>::from_be_bytes(exact(Self::KIND, bytes)?);
+                Self::try_from(w).map_err(|_| SodgError::TooBig {
+                    kind: Self::KIND,
+                    value: i128::from(w),
+                })
+            }
+
+            fn from_le_slice(bytes: &[u8]) -> Result<Self, SodgError> {
+                let w = <$wide>::from_le_bytes(exact(Self::KIND, bytes)?);
+                Self::try_from(w).map_err(|_| SodgError::TooBig {
+                    kind: Self::KIND,
+                    value: i128::from(w),
+                })
+            }
+        }
+
+        impl TryFrom<&Hex> for $t {
+            type Error = SodgError;
+
+            /// Take the number back from big-endian bytes of a [`Hex`].
+            fn try_from(h: &Hex) -> Result<Self, Self::Error> {
+                h.to_be()
+            }
+        }
+    };
+}
+
+/// Make a [`Hex`] from big-endian bytes of a number, for the types
+/// that don't have such a conversion in `hex.rs`.
+macro_rules! from_numeric {
+    ($t:ty) => {
+        impl From<$t> for Hex {
+            /// Make a new `Hex` from big-endian bytes of the number.
+            fn from(d: $t) -> Self {
+                d.to_be_hex()
+            }
+        }
+    };
+}
+
+numeric!(i8, "I8");
+numeric!(i16, "I16");
+numeric!(i32, "I32");
+numeric!(i64, "INT");
+numeric!(i128, "I128");
+numeric_wide!(isize, i64, "ISIZE");
+numeric!(u8, "U8");
+numeric!(u16, "U16");
+numeric!(u32, "U32");
+numeric!(u64, "U64");
+numeric!(u128, "U128");
+numeric_wide!(usize, u64, "USIZE");
+numeric!(f32, "F32");
+numeric!(f64, "FLOAT");
+
+from_numeric!(i128);
+from_numeric!(isize);
+from_numeric!(u8);
+from_numeric!(u16);
+from_numeric!(u32);
+from_numeric!(u64);
+from_numeric!(u128);
+from_numeric!(usize);
+from_numeric!(char);
+
+impl Numeric for char {
+    const KIND: &'static str = "CHAR";
+
+    fn to_be_hex(self) -> Hex {
+        u32::from(self).to_be_hex()
+    }
+
+    fn to_le_hex(self) -> Hex {
+        u32::from(self).to_le_hex()
+    }
+
+    fn from_be_slice(bytes: &[u8]) -> Result<Self, SodgError> {
+        let c = u32::from_be_bytes(exact(Self::KIND, bytes)?);
+        Self::from_u32(c).ok_or(SodgError::BadChar(c))
+    }
+
+    fn from_le_slice(bytes: &[u8]) -> Result<Self, SodgError> {
+        let c = u32::from_le_bytes(exact(Self::KIND, bytes)?);
+        Self::from_u32(c).ok_or(SodgError::BadChar(c))
+    }
+}
+
+impl TryFrom<&Hex> for char {
+    type Error = SodgError;
+
+    /// Take the character back from big-endian bytes of a [`Hex`].
+    fn try_from(h: &Hex) -> Result<Self, Self::Error> {
+        h.to_be()
+    }
+}
+
+impl Hex {
+    /// Make a new `Hex` from big-endian bytes of a number.
+    ///
+    /// It is the same as `Hex::from`, for example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// assert_eq!(Hex::from(42_u32), Hex::from_be(42_u32));
+    /// ```
+    #[must_use]
+    pub fn from_be<T: Numeric>(d: T) -> Self {
+        d.to_be_hex()
+    }
+
+    /// Make a new `Hex` from little-endian bytes of a number.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// assert_eq!("2A-00-00-00", Hex::from_le(42_u32).print());
+    /// ```
+    #[must_use]
+    pub fn from_le<T: Numeric>(d: T) -> Self {
+        d.to_le_hex()
+    }
+
+    /// Turn big-endian bytes into a number.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// let d = Hex::from(u128::MAX);
+    /// assert_eq!(u128::MAX, d.to_be::<u128>().unwrap());
+    /// assert!(d.to_be::<u64>().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the number of bytes doesn't match the type, an error will be returned.
+    pub fn to_be<T: Numeric>(&self) -> Result<T, SodgError> {
+        T::from_be_slice(self.bytes())
+    }
+
+    /// Turn little-endian bytes into a number.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// let d = Hex::from_le('ж');
+    /// assert_eq!('ж', d.to_le::<char>().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the number of bytes doesn't match the type, an error will be returned.
+    pub fn to_le<T: Numeric>(&self) -> Result<T, SodgError> {
+        T::from_le_slice(self.bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_unsigned() {
+        assert_eq!(255_u8, u8::try_from(&Hex::from(255_u8)).unwrap());
+        assert_eq!(65535_u16, u16::try_from(&Hex::from(65535_u16)).unwrap());
+        assert_eq!(u32::MAX, u32::try_from(&Hex::from(u32::MAX)).unwrap());
+        assert_eq!(u64::MAX, u64::try_from(&Hex::from(u64::MAX)).unwrap());
+        assert_eq!(u128::MAX, u128::try_from(&Hex::from(u128::MAX)).unwrap());
+        assert_eq!(usize::MAX, usize::try_from(&Hex::from(usize::MAX)).unwrap());
+    }
+
+    #[test]
+    fn round_trips_signed() {
+        assert_eq!(-1_i8, i8::try_from(&Hex::from(-1_i8)).unwrap());
+        assert_eq!(i128::MIN, i128::try_from(&Hex::from(i128::MIN)).unwrap());
+        assert_eq!(-42_isize, isize::try_from(&Hex::from(-42_isize)).unwrap());
+        assert_eq!(-42, i64::try_from(&Hex::from(-42_i64)).unwrap());
+    }
+
+    #[test]
+    fn round_trips_floats() {
+        let f = std::f32::consts::E;
+        assert!((f - f32::try_from(&Hex::from(f)).unwrap()).abs() < f32::EPSILON);
+        assert_eq!(
+            std::f64::consts::PI.to_bits(),
+            Hex::from_le(std::f64::consts::PI)
+                .to_le::<f64>()
+                .unwrap()
+                .to_bits()
+        );
+    }
+
+    #[test]
+    fn round_trips_chars() {
+        let d = Hex::from('Ж');
+        assert_eq!("00-00-04-16", d.print());
+        assert_eq!('Ж', char::try_from(&d).unwrap());
+        assert_eq!('Ж', d.tail(0).to_be::<char>().unwrap());
+    }
+
+    #[test]
+    fn keeps_byte_order() {
+        let d = Hex::from_le(0x0102_0304_u32);
+        assert_eq!("04-03-02-01", d.print());
+        assert_eq!(0x0102_0304, d.to_le::<u32>().unwrap());
+        assert_eq!(0x0403_0201, d.to_be::<u32>().unwrap());
+    }
+
+    #[test]
+    fn explains_wrong_length() {
+        let d = Hex::from(42_u16);
+        assert_eq!(
+            "Wrong number of bytes, can't make U32 (just 2 while we need 4)",
+            u32::try_from(&d).unwrap_err().to_string()
+        );
+        assert!(matches!(
+            i64::try_from(&d),
+            Err(SodgError::HexLength { kind: "INT", .. })
+        ));
+    }
+
+    #[test]
+    fn takes_eight_bytes_for_usize() {
+        assert_eq!("00-00-00-00-00-00-00-2A", Hex::from(42_usize).print());
+        assert_eq!(8, Hex::from_le(-1_isize).len());
+        assert_eq!(42, Hex::from(42_u64).to_be::<usize>().unwrap());
+        assert_eq!(-7, Hex::from_le(-7_i64).to_le::<isize>().unwrap());
+        assert!(Hex::from(42_u32).to_be::<usize>().is_err());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn refuses_too_big_usize() {
+        assert!(matches!(
+            Hex::from(u64::MAX).to_be::<usize>(),
+            Err(SodgError::TooBig { kind: "USIZE", .. })
+        ));
+    }
+
+    #[test]
+    fn refuses_broken_char() {
+        let d = Hex::from(0xD800_u32);
+        assert!(matches!(
+            char::try_from(&d),
+            Err(SodgError::BadChar(0xD800))
+        ));
+    }
+}
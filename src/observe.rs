@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use crate::{Observer, Sodg};
+
+impl<const N: usize> Sodg<N> {
+    /// Register an observer, which will be told about the changes in the graph.
+    ///
+    /// The observers are called in the order of their registration,
+    /// right after the change is made. They are neither saved
+    /// by [`Sodg::save`] nor cloned. For example:
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// use sodg::{Hex, Observer, Sodg};
+    /// struct Counter(Rc<RefCell<usize>>);
+    /// impl Observer for Counter {
+    ///     fn on_put(&mut self, _v: usize, _d: &Hex) {
+    ///         *self.0.borrow_mut() += 1;
+    ///     }
+    /// }
+    /// let puts = Rc::new(RefCell::new(0));
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.observe(Counter(puts.clone()));
+    /// g.add(0);
+    /// g.put(0, &Hex::from(42));
+    /// assert_eq!(1, *puts.borrow());
+    /// ```
+    pub fn observe<O: Observer + 'static>(&mut self, o: O) {
+        self.observers.push(Box::new(o));
+    }
+
+    /// Tell all observers about a change.
+    pub(crate) fn notify<F: FnMut(&mut dyn Observer)>(&mut self, mut f: F) {
+        for o in &mut self.observers {
+            f(o.as_mut());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::{Hex, Label};
+
+    #[derive(Default)]
+    struct Journal {
+        collected: Vec<usize>,
+        puts: Vec<usize>,
+        binds: Vec<(usize, usize, Label)>,
+    }
+
+    struct Spy(Rc<RefCell<Journal>>);
+
+    impl Observer for Spy {
+        fn on_collect(&mut self, vs: &[usize]) {
+            self.0.borrow_mut().collected.extend(vs);
+        }
+
+        fn on_put(&mut self, v: usize, _d: &Hex) {
+            self.0.borrow_mut().puts.push(v);
+        }
+
+        fn on_bind(&mut self, v1: usize, v2: usize, a: Label) {
+            self.0.borrow_mut().binds.push((v1, v2, a));
+        }
+    }
+
+    #[test]
+    fn tells_about_binds_and_puts() {
+        let journal = Rc::new(RefCell::new(Journal::default()));
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.observe(Spy(journal.clone()));
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        g.put(1, &Hex::from(42));
+        assert!(g.try_bind(0, 7, Label::Alpha(1)).is_err());
+        assert_eq!(vec![(0, 1, Label::Alpha(0))], journal.borrow().binds);
+        assert_eq!(vec![1], journal.borrow().puts);
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn tells_about_collected_vertices() {
+        let journal = Rc::new(RefCell::new(Journal::default()));
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.observe(Spy(journal.clone()));
+        for v in 0..3 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(1, 2, Label::Alpha(0));
+        g.put(2, &Hex::from(42));
+        g.data(2);
+        let mut gone = journal.borrow().collected.clone();
+        gone.sort_unstable();
+        assert_eq!(vec![0, 1, 2], gone);
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn tells_about_kid_orphaned_by_remove() {
+        let journal = Rc::new(RefCell::new(Journal::default()));
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.observe(Spy(journal.clone()));
+        for v in 0..3 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(1, 2, Label::Alpha(0));
+        g.put(0, &Hex::from(42));
+        g.remove(1, crate::Dangling::Unbind);
+        assert!(journal.borrow().collected.is_empty());
+        g.set_root(0);
+        g.collect();
+        assert_eq!(vec![2], journal.borrow().collected);
+    }
+
+    #[test]
+    fn forgets_observers_in_clone() {
+        let journal = Rc::new(RefCell::new(Journal::default()));
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.observe(Spy(journal.clone()));
+        let mut c = g.clone();
+        c.add(0);
+        c.put(0, &Hex::from(42));
+        assert!(journal.borrow().puts.is_empty());
+    }
+}
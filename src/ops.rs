@@ -1,12 +1,11 @@
 // SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
 // SPDX-License-Identifier: MIT
 
-use anyhow::Context as _;
 #[cfg(debug_assertions)]
 use log::trace;
 
-use crate::{BRANCH_NONE, BRANCH_STATIC, Persistence, Sodg};
-use crate::{Hex, Label};
+use crate::{BRANCH_NONE, BRANCH_STATIC, Persistence, Sodg, Vertex};
+use crate::{Hex, Label, SodgError};
 
 impl<const N: usize> Sodg<N> {
     /// Add a new vertex `v1` to itself.
@@ -26,12 +25,54 @@ impl<const N: usize> Sodg<N> {
     ///
     /// # Panics
     ///
-    /// If alerts trigger any error, the error will be returned here.
+    /// If [`Sodg::try_add`] returns an error, it will panic.
     #[inline]
     pub fn add(&mut self, v1: usize) {
-        self.vertices.get_mut(v1).unwrap().branch = 1;
+        self.try_add(v1).unwrap();
+    }
+
+    /// Add a new vertex `v1` to itself, or explain why it's impossible.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Sodg;
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// assert!(g.try_add(42).is_ok());
+    /// assert!(g.try_add(256).is_ok());
+    /// assert!(g.try_add(usize::MAX).is_err());
+    /// ```
+    ///
+    /// If vertex `v1` already exists in the graph, nothing will happen.
+    /// If `v1` is beyond the capacity of the graph, the graph grows.
+    /// If `v1` was destroyed as garbage, it comes back without edges and data.
+    /// If an error is returned, the graph stays intact.
+    ///
+    /// # Errors
+    ///
+    /// If `v1` is too big for the graph to grow that far, an `Err` will be returned.
+    #[inline]
+    pub fn try_add(&mut self, v1: usize) -> Result<(), SodgError> {
+        self.grow(v1)?;
+        self.occupy(v1);
+        self.touch(v1);
+        if self.vertex(v1).is_err() {
+            self.alive += 1;
+            let mut fresh = Vertex::empty();
+            fresh.branch = BRANCH_STATIC;
+            if self.vertices.contains_key(v1) {
+                fresh.listed = self.vertices[v1].listed;
+                let dead = std::mem::replace(&mut self.vertices[v1], fresh);
+                for (a, to) in dead.edges.iter() {
+                    self.unlink(v1, *a, *to);
+                }
+            } else {
+                self.vertices.insert(v1, fresh);
+            }
+        }
         #[cfg(debug_assertions)]
         trace!("#add: vertex ν{v1} added");
+        Ok(())
     }
 
     /// Make an edge `e1` from vertex `v1` to vertex `v2` and put `a` label on it.
@@ -52,51 +93,149 @@ impl<const N: usize> Sodg<N> {
     ///
     /// # Panics
     ///
+    /// If [`Sodg::try_bind`] returns an error, it will panic.
+    #[inline]
+    pub fn bind(&mut self, v1: usize, v2: usize, a: Label) {
+        self.try_bind(v1, v2, a).unwrap();
+    }
+
+    /// Make an edge from vertex `v1` to vertex `v2` and put `a` label on it,
+    /// or explain why it's impossible.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(42);
+    /// assert!(g.try_bind(0, 42, Label::Alpha(0)).is_ok());
+    /// assert!(g.try_bind(0, 0, Label::Alpha(1)).is_err());
+    /// assert!(g.try_bind(0, 7, Label::Alpha(2)).is_err());
+    /// ```
+    ///
+    /// If an edge with this label already exists, it will be replaced with a new edge.
+    /// If an error is returned, the graph stays intact.
+    ///
+    /// # Errors
+    ///
     /// If either vertex `v1` or `v2` is absent, an `Err` will be returned.
     ///
     /// If `v1` equals to `v2`, an `Err` will be returned.
     ///
-    /// The label `a` can't be empty. If it is empty, an `Err` will be returned.
-    ///
-    /// If alerts trigger any error, the error will be returned here.
+    /// If there are no free branches left, an `Err` will be returned.
     #[inline]
-    pub fn bind(&mut self, v1: usize, v2: usize, a: Label) {
-        let mut ours = self.vertices.get(v1).unwrap().branch;
-        let theirs = self.vertices.get(v2).unwrap().branch;
-        let vtx1 = self.vertices.get_mut(v1).unwrap();
-        vtx1.edges.insert(a, v2);
-        if ours == BRANCH_STATIC {
-            if theirs == BRANCH_STATIC {
-                for b in self.branches.iter_mut() {
-                    if b.1.is_empty() {
-                        b.1.push(v1);
-                        ours = b.0;
-                        vtx1.branch = ours;
-                        break;
-                    }
-                }
-                self.vertices.get_mut(v2).unwrap().branch = ours;
-                self.branches.get_mut(ours).unwrap().push(v2);
-            } else {
-                vtx1.branch = theirs;
-                self.branches.get_mut(theirs).unwrap().push(v1);
-            }
+    pub fn try_bind(&mut self, v1: usize, v2: usize, a: Label) -> Result<(), SodgError> {
+        self.vertex(v1)?;
+        self.vertex(v2)?;
+        if v1 == v2 {
+            return Err(SodgError::SelfLoop { v: v1, label: a });
+        }
+        #[cfg(feature = "gc")]
+        let target = if self.is_weak(a) {
+            None
         } else {
-            let vtx2 = self.vertices.get_mut(v2).unwrap();
-            if vtx2.branch == BRANCH_STATIC {
-                vtx2.branch = ours;
-                self.branches.get_mut(ours).unwrap().push(v2);
+            Some(self.plan(v1, v2)?)
+        };
+        self.touch(v1);
+        let prev = self.vertices[v1].edges.insert(a, v2);
+        if prev.is_none() {
+            self.arrows += 1;
+        }
+        #[cfg(feature = "gc")]
+        if let Some(b) = target {
+            self.enter(b, &[v1, v2]);
+        }
+        if prev != Some(v2) {
+            if let Some(old) = prev {
+                self.unlink(v1, a, old);
             }
+            self.link(v1, a, v2);
         }
         #[cfg(debug_assertions)]
         trace!(
             "#bind: edge added ν{}(b={}).{} → ν{}(b={})",
-            v1,
-            self.vertices.get(v1).unwrap().branch,
-            a,
-            v2,
-            self.vertices.get(v2).unwrap().branch,
+            v1, self.vertices[v1].branch, a, v2, self.vertices[v2].branch,
+        );
+        self.notify(|o| o.on_bind(v1, v2, a));
+        Ok(())
+    }
+
+    /// Remove an edge labeled `a` departing from vertex `v`, and return
+    /// the ID of the vertex it was pointing to.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(42);
+    /// g.bind(0, 42, Label::Alpha(0));
+    /// assert_eq!(Some(42), g.unbind(0, Label::Alpha(0)));
+    /// assert_eq!(None, g.unbind(0, Label::Alpha(0)));
+    /// ```
+    ///
+    /// If there is no such edge, `None` will be returned and nothing will happen.
+    /// If the vertex the edge was pointing to is not reachable from the branch
+    /// of `v` anymore, it leaves the branch, together with its kids, and moves
+    /// to a branch of its own. It stays alive there, so that it can be bound again,
+    /// until its data is read by [`Sodg::data`] or it is destroyed by [`Sodg::collect`].
+    ///
+    /// # Panics
+    ///
+    /// If [`Sodg::try_unbind`] returns an error, it will panic.
+    #[inline]
+    pub fn unbind(&mut self, v: usize, a: Label) -> Option<usize> {
+        self.try_unbind(v, a).unwrap()
+    }
+
+    /// Remove an edge labeled `a` departing from vertex `v`, and return
+    /// the ID of the vertex it was pointing to, or explain why it's impossible.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// assert_eq!(None, g.try_unbind(0, Label::Alpha(0)).unwrap());
+    /// assert!(g.try_unbind(1, Label::Alpha(0)).is_err());
+    /// ```
+    ///
+    /// If an error is returned, the graph stays intact.
+    ///
+    /// # Errors
+    ///
+    /// If vertex `v` is absent, an `Err` will be returned.
+    ///
+    /// If the branch has to be split and there are no free branches left,
+    /// an `Err` will be returned.
+    #[inline]
+    pub fn try_unbind(&mut self, v: usize, a: Label) -> Result<Option<usize>, SodgError> {
+        let vtx = self.vertex_mut(v)?;
+        let Some(to) = vtx.edges.remove(&a) else {
+            return Ok(None);
+        };
+        #[cfg(feature = "gc")]
+        {
+            let branch = vtx.branch;
+            if self.vertices[to].branch == branch
+                && !self.is_weak(a)
+                && let Err(e) = self.split(branch, &[v, to])
+            {
+                self.vertices[v].edges.insert(a, to);
+                return Err(e);
+            }
+        }
+        self.unlink(v, a, to);
+        self.arrows -= 1;
+        #[cfg(debug_assertions)]
+        trace!(
+            "#unbind: edge removed ν{}(b={}).{} → ν{}(b={})",
+            v, self.vertices[v].branch, a, to, self.vertices[to].branch,
         );
+        Ok(Some(to))
     }
 
     /// Set vertex data.
@@ -113,17 +252,44 @@ impl<const N: usize> Sodg<N> {
     ///
     /// # Panics
     ///
-    /// If vertex `v1` is absent, an `Err` will be returned.
-    ///
-    /// If alerts trigger any error, the error will be returned here.
+    /// If [`Sodg::try_put`] returns an error, it will panic.
     #[inline]
     pub fn put(&mut self, v: usize, d: &Hex) {
-        let vtx = self.vertices.get_mut(v).unwrap();
+        self.try_put(v, d).unwrap();
+    }
+
+    /// Set vertex data, or explain why it's impossible.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// use sodg::Sodg;
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(42);
+    /// assert!(g.try_put(42, &Hex::from(1)).is_ok());
+    /// assert!(g.try_put(43, &Hex::from(1)).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If vertex `v` is absent, an `Err` will be returned.
+    #[inline]
+    pub fn try_put(&mut self, v: usize, d: &Hex) -> Result<(), SodgError> {
+        let vtx = self.vertex_mut(v)?;
+        #[cfg(feature = "gc")]
+        let fresh = vtx.persistence != Persistence::Stored;
         vtx.persistence = Persistence::Stored;
         vtx.data = d.clone();
-        *self.stores.get_mut(vtx.branch).unwrap() += 1;
+        #[cfg(feature = "gc")]
+        if fresh && vtx.branch != BRANCH_STATIC {
+            let branch = vtx.branch;
+            self.stores[branch] += 1;
+        }
         #[cfg(debug_assertions)]
         trace!("#put: data of ν{v} set to {d}");
+        self.notify(|o| o.on_put(v, d));
+        Ok(())
     }
 
     /// Read vertex data, and then submit the vertex to garbage collection.
@@ -151,34 +317,40 @@ impl<const N: usize> Sodg<N> {
     ///
     /// # Panics
     ///
-    /// If vertex `v1` is absent, it will panic.
+    /// If [`Sodg::try_data`] returns an error, it will panic.
     #[inline]
     pub fn data(&mut self, v: usize) -> Option<Hex> {
-        let vtx = self.vertices.get_mut(v).unwrap();
-        match vtx.persistence {
+        self.try_data(v).unwrap()
+    }
+
+    /// Read vertex data, and then submit the vertex to garbage collection,
+    /// or explain why it's impossible.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Hex;
+    /// use sodg::Sodg;
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(42);
+    /// assert!(g.try_data(42).unwrap().is_none());
+    /// assert!(g.try_data(43).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If vertex `v` is absent, an `Err` will be returned.
+    #[inline]
+    pub fn try_data(&mut self, v: usize) -> Result<Option<Hex>, SodgError> {
+        let vtx = self.vertex_mut(v)?;
+        Ok(match vtx.persistence {
             Persistence::Stored => {
                 let d = vtx.data.clone();
                 vtx.persistence = Persistence::Taken;
-                let branch = vtx.branch;
-                let s = self.stores.get_mut(branch).unwrap();
-                *s -= 1;
-                if *s == 0 {
-                    let members = self.branches.get_mut(branch).unwrap();
-                    for v in members.into_iter() {
-                        self.vertices.get_mut(v).unwrap().branch = BRANCH_NONE;
-                    }
-                    #[cfg(debug_assertions)]
-                    trace!(
-                        "#data: branch no.{} destroyed {} vertices as garbage: {}",
-                        branch,
-                        members.len(),
-                        members
-                            .into_iter()
-                            .map(|v| format!("ν{v}"))
-                            .collect::<Vec<String>>()
-                            .join(", ")
-                    );
-                    members.clear();
+                #[cfg(feature = "gc")]
+                {
+                    let branch = vtx.branch;
+                    self.release(branch);
                 }
                 #[cfg(debug_assertions)]
                 trace!("#data: data of ν{v} retrieved");
@@ -190,7 +362,55 @@ impl<const N: usize> Sodg<N> {
                 Some(vtx.data.clone())
             }
             Persistence::Empty => None,
+        })
+    }
+
+    /// Read vertex data without any side effects.
+    ///
+    /// Unlike [`Sodg::data`], it doesn't mark the data as taken and
+    /// never submits the vertex to garbage collection, so it's safe to
+    /// use in debuggers and logs:
+    ///
+    /// ```
+    /// use sodg::{Hex, Persistence, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(42);
+    /// g.put(42, &Hex::from_str_bytes("hello"));
+    /// assert_eq!("hello", g.peek(42).unwrap().to_utf8().unwrap());
+    /// assert_eq!(Some(Persistence::Stored), g.persistence(42));
+    /// ```
+    ///
+    /// If the vertex is absent or has no data, `None` is returned.
+    #[must_use]
+    #[inline]
+    pub fn peek(&self, v: usize) -> Option<&Hex> {
+        let vtx = self.vertex(v).ok()?;
+        if vtx.persistence == Persistence::Empty {
+            return None;
         }
+        Some(&vtx.data)
+    }
+
+    /// Find out the state of the data in a vertex.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Persistence, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(42);
+    /// assert_eq!(Some(Persistence::Empty), g.persistence(42));
+    /// g.put(42, &Hex::from(7));
+    /// g.data(42);
+    /// assert_eq!(Some(Persistence::Taken), g.persistence(42));
+    /// assert_eq!(None, g.persistence(43));
+    /// ```
+    ///
+    /// If the vertex is absent, `None` is returned.
+    #[must_use]
+    #[inline]
+    pub fn persistence(&self, v: usize) -> Option<Persistence> {
+        self.vertex(v).ok().map(|vtx| vtx.persistence)
     }
 
     /// Find all kids of a vertex.
@@ -228,15 +448,35 @@ impl<const N: usize> Sodg<N> {
     ///
     /// # Panics
     ///
-    /// If vertex `v1` is absent, `Err` will be returned.
+    /// If [`Sodg::try_kids`] returns an error, it will panic.
     #[inline]
     pub fn kids(&self, v: usize) -> impl Iterator<Item = (&Label, &usize)> + '_ {
-        self.vertices
-            .get(v)
-            .with_context(|| format!("Can't find ν{v} in kids()"))
-            .unwrap()
-            .edges
-            .iter()
+        self.try_kids(v).unwrap()
+    }
+
+    /// Find all kids of a vertex, or explain why it's impossible.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(42);
+    /// g.bind(0, 42, Label::Alpha(0));
+    /// assert_eq!(1, g.try_kids(0).unwrap().count());
+    /// assert!(g.try_kids(7).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If vertex `v` is absent, an `Err` will be returned.
+    #[inline]
+    pub fn try_kids(
+        &self,
+        v: usize,
+    ) -> Result<impl Iterator<Item = (&Label, &usize)> + '_, SodgError> {
+        Ok(self.vertex(v)?.edges.iter())
     }
 
     /// Find a kid of a vertex, by its edge name, and return the ID of the vertex found.
@@ -254,19 +494,68 @@ impl<const N: usize> Sodg<N> {
     /// assert_eq!(42, g.kid(0, k).unwrap());
     /// ```
     ///
-    /// # Panics
-    ///
-    /// If vertex `v1` is absent, it will panic.
+    /// If vertex `v` is beyond the capacity of the graph, `None` will be returned.
     #[must_use]
     #[inline]
     pub fn kid(&self, v: usize, a: Label) -> Option<usize> {
-        for e in &self.vertices.get(v).unwrap().edges {
+        if v >= self.vertices.capacity() {
+            return None;
+        }
+        for e in self.vertices.get(v)?.edges.iter() {
             if *e.0 == a {
                 return Some(*e.1);
             }
         }
         None
     }
+
+    /// Find a kid of a vertex, by its edge name, or explain why it's impossible.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(42);
+    /// g.bind(0, 42, Label::Alpha(0));
+    /// assert_eq!(Some(42), g.try_kid(0, Label::Alpha(0)).unwrap());
+    /// assert_eq!(None, g.try_kid(0, Label::Alpha(1)).unwrap());
+    /// assert!(g.try_kid(7, Label::Alpha(0)).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If vertex `v` is absent, an `Err` will be returned.
+    #[inline]
+    pub fn try_kid(&self, v: usize, a: Label) -> Result<Option<usize>, SodgError> {
+        Ok(self.vertex(v)?.edges.get(&a).copied())
+    }
+
+    /// Find the vertex `v`, making sure it exists in the graph.
+    #[inline]
+    pub(crate) fn vertex(&self, v: usize) -> Result<&Vertex<N>, SodgError> {
+        if v >= self.vertices.capacity() {
+            return Err(SodgError::VertexAbsent(v));
+        }
+        match self.vertices.get(v) {
+            Some(vtx) if vtx.branch != BRANCH_NONE => Ok(vtx),
+            _ => Err(SodgError::VertexAbsent(v)),
+        }
+    }
+
+    /// Find the vertex `v` for modification, making sure it exists in the graph.
+    #[inline]
+    pub(crate) fn vertex_mut(&mut self, v: usize) -> Result<&mut Vertex<N>, SodgError> {
+        if v >= self.vertices.capacity() {
+            return Err(SodgError::VertexAbsent(v));
+        }
+        self.touch(v);
+        match self.vertices.get_mut(v) {
+            Some(vtx) if vtx.branch != BRANCH_NONE => Ok(vtx),
+            _ => Err(SodgError::VertexAbsent(v)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -285,24 +574,38 @@ mod tests {
     }
 
     #[test]
+    fn refuses_too_big_id() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.add(0);
+        assert!(matches!(
+            g.try_add(usize::MAX),
+            Err(SodgError::IdTooBig(usize::MAX))
+        ));
+        assert_eq!(1, g.len());
+        assert_eq!(16, g.capacity());
+        assert_eq!(1, g.next_id());
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
     fn sets_branch_correctly() {
         let mut g: Sodg<16> = Sodg::empty(256);
         g.add(1);
         g.add(2);
         g.bind(1, 2, Label::Alpha(0));
-        assert_eq!(1, g.branches.get(1).unwrap().len());
-        assert_eq!(2, g.branches.get(2).unwrap().len());
+        assert!(g.branches[1].is_empty());
+        assert_eq!(2, g.branches[2].len());
         g.put(2, &Hex::from(42));
-        assert_eq!(&1, g.stores.get(2).unwrap());
+        assert_eq!(1, g.stores[2]);
         g.add(3);
         g.bind(1, 3, Label::Alpha(1));
-        assert_eq!(3, g.branches.get(2).unwrap().len());
+        assert_eq!(3, g.branches[2].len());
         g.add(4);
         g.add(5);
         g.bind(4, 5, Label::Alpha(0));
-        assert_eq!(2, g.branches.get(3).unwrap().len());
+        assert_eq!(2, g.branches[3].len());
         g.data(2);
-        assert_eq!(0, g.branches.get(2).unwrap().len());
+        assert_eq!(0, g.branches[2].len());
     }
 
     #[test]
@@ -359,6 +662,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "gc")]
     fn collects_garbage() {
         let mut g: Sodg<16> = Sodg::empty(256);
         g.add(1);
@@ -368,7 +672,7 @@ mod tests {
         g.add(3);
         g.bind(1, 3, Label::Alpha(0));
         assert_eq!(3, g.len());
-        assert_eq!(3, g.branches.get(2).unwrap().len());
+        assert_eq!(3, g.branches[2].len());
         g.data(2);
         assert_eq!(0, g.len());
     }
@@ -428,4 +732,200 @@ mod tests {
         g.add(0);
         g.add(0);
     }
+
+    #[test]
+    fn adds_beyond_capacity() {
+        let mut g: Sodg<16> = Sodg::empty(4);
+        g.add(3);
+        g.add(4);
+        assert_eq!(2, g.len());
+        assert!(matches!(g.try_kids(100), Err(SodgError::VertexAbsent(100))));
+    }
+
+    #[test]
+    fn refuses_to_bind_absent_vertex() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(1);
+        assert!(matches!(
+            g.try_bind(1, 2, Label::Alpha(0)),
+            Err(SodgError::VertexAbsent(2))
+        ));
+        assert!(matches!(
+            g.try_bind(2, 1, Label::Alpha(0)),
+            Err(SodgError::VertexAbsent(2))
+        ));
+        assert_eq!(0, g.kids(1).count());
+    }
+
+    #[test]
+    fn refuses_to_bind_to_itself() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(1);
+        assert!(matches!(
+            g.try_bind(1, 1, Label::Alpha(0)),
+            Err(SodgError::SelfLoop { v: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn binds_more_edges_than_inline() {
+        let mut g: Sodg<2> = Sodg::empty(256);
+        for v in 0..10 {
+            g.add(v);
+        }
+        for v in 1..10 {
+            g.bind(0, v, Label::Alpha(v));
+        }
+        g.bind(0, 9, Label::Alpha(1));
+        assert_eq!(9, g.kids(0).count());
+        assert_eq!(Some(9), g.kid(0, Label::Alpha(1)));
+        assert_eq!(Some(5), g.kid(0, Label::Alpha(5)));
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn refuses_to_overflow_branches() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.limit_branches(4);
+        for v in 0..16 {
+            g.add(v);
+        }
+        let mut v = 0;
+        let e = loop {
+            if let Err(e) = g.try_bind(v, v + 1, Label::Alpha(0)) {
+                break e;
+            }
+            v += 2;
+        };
+        assert!(matches!(e, SodgError::BranchesFull));
+        assert!(g.kid(v, Label::Alpha(0)).is_none());
+    }
+
+    #[test]
+    fn refuses_to_touch_absent_vertex() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        assert!(g.try_put(0, &Hex::from(42)).is_err());
+        assert!(g.try_data(0).is_err());
+        assert!(g.try_kid(0, Label::Alpha(0)).is_err());
+        assert!(g.try_kids(0).is_err());
+        assert!(g.try_data(1000).is_err());
+    }
+
+    #[test]
+    fn unbinds_edge() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(1);
+        g.add(2);
+        g.bind(1, 2, Label::Alpha(0));
+        assert_eq!(Some(2), g.unbind(1, Label::Alpha(0)));
+        assert!(g.kid(1, Label::Alpha(0)).is_none());
+        assert_eq!(None, g.unbind(1, Label::Alpha(0)));
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn keeps_unbound_kid_alive() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(1);
+        g.add(2);
+        g.bind(1, 2, Label::Alpha(0));
+        g.add(3);
+        g.bind(2, 3, Label::Alpha(0));
+        assert_eq!(3, g.len());
+        g.unbind(1, Label::Alpha(0));
+        assert_eq!(3, g.len());
+        assert_eq!(1, g.branches[2].len());
+        assert_eq!(2, g.branches[3].len());
+        g.set_root(1);
+        assert_eq!(vec![2, 3], g.collect());
+        assert_eq!(1, g.len());
+    }
+
+    #[test]
+    fn binds_unbound_kid_again() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        for v in 0..3 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        let old = g.unbind(0, Label::Alpha(0)).unwrap();
+        g.bind(2, old, Label::Alpha(0));
+        assert_eq!(Some(1), g.kid(2, Label::Alpha(0)));
+        assert_eq!(3, g.len());
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn counts_stores_once_per_vertex() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(1);
+        g.add(2);
+        g.bind(1, 2, Label::Alpha(0));
+        g.put(2, &Hex::from(1));
+        g.put(2, &Hex::from(2));
+        assert_eq!(1, g.stores[2]);
+        g.data(2);
+        assert_eq!(0, g.len());
+    }
+
+    #[test]
+    #[cfg(not(feature = "gc"))]
+    fn keeps_everything_without_gc() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        for v in 0..64 {
+            g.add(v);
+        }
+        for v in 1..64 {
+            g.bind(v - 1, v, Label::Alpha(0));
+        }
+        g.put(63, &Hex::from(42_i64));
+        assert_eq!(42, g.data(63).unwrap().to_i64().unwrap());
+        g.unbind(0, Label::Alpha(0));
+        assert_eq!(64, g.len());
+        assert_eq!(Some(Persistence::Taken), g.persistence(63));
+        assert_eq!(BRANCH_STATIC, g.vertices.get(63).unwrap().branch);
+    }
+
+    #[test]
+    fn binds_long_chain() {
+        let mut g: Sodg<16> = Sodg::empty(10_000);
+        g.add(0);
+        for v in 1..10_000 {
+            g.add(v);
+            g.bind(v - 1, v, Label::Alpha(0));
+        }
+        assert_eq!(10_000, g.len());
+        g.put(9_999, &Hex::from(42));
+        assert!(g.data(9_999).is_some());
+        #[cfg(feature = "gc")]
+        assert_eq!(0, g.len());
+    }
+
+    #[test]
+    fn peeks_without_collecting() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(1);
+        g.add(2);
+        g.bind(1, 2, Label::Alpha(0));
+        g.put(2, &Hex::from(42_i64));
+        assert_eq!(42, g.peek(2).unwrap().to_i64().unwrap());
+        assert_eq!(42, g.peek(2).unwrap().to_i64().unwrap());
+        assert_eq!(Some(Persistence::Stored), g.persistence(2));
+        #[cfg(feature = "gc")]
+        assert_eq!(1, g.stores[2]);
+        assert_eq!(2, g.len());
+        assert!(g.peek(1).is_none());
+        assert!(g.peek(42).is_none());
+    }
+
+    #[test]
+    fn keeps_branch_when_added_twice() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(1);
+        g.add(2);
+        g.bind(1, 2, Label::Alpha(0));
+        let before = g.vertices.get(1).unwrap().branch;
+        g.add(1);
+        assert_eq!(before, g.vertices.get(1).unwrap().branch);
+    }
 }
@@ -0,0 +1,231 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use itertools::Either;
+
+use crate::{BRANCH_NONE, Label, Sodg};
+
+impl<const N: usize> Sodg<N> {
+    /// Start maintaining the index of parents, which makes
+    /// [`Sodg::parents`] and [`Sodg::in_degree`] work in constant time,
+    /// instead of scanning all vertices.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.index_parents();
+    /// g.add(0);
+    /// g.add(42);
+    /// g.bind(0, 42, Label::Alpha(0));
+    /// assert_eq!(vec![(0, Label::Alpha(0))], g.parents(42).collect::<Vec<_>>());
+    /// ```
+    ///
+    /// The index is built from the edges already in the graph, and then
+    /// maintained by [`Sodg::bind`], [`Sodg::unbind`], and [`Sodg::remove`].
+    /// If the index is already maintained, nothing will happen.
+    pub fn index_parents(&mut self) {
+        if self.indexed {
+            return;
+        }
+        let mut all = vec![];
+        for (v, vtx) in self.vertices.iter() {
+            if vtx.branch == BRANCH_NONE {
+                continue;
+            }
+            for (a, to) in vtx.edges.iter() {
+                all.push((v, *a, *to));
+            }
+        }
+        self.indexed = true;
+        for (v, a, to) in all {
+            self.touch(to);
+            if let Some(vtx) = self.vertices.get_mut(to) {
+                vtx.parents.push((v, a));
+            }
+        }
+    }
+
+    /// Find all vertices pointing to the vertex `v`, together with
+    /// the labels of their edges.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(42);
+    /// g.bind(0, 42, Label::Alpha(0));
+    /// g.bind(1, 42, Label::Alpha(1));
+    /// assert_eq!(2, g.parents(42).count());
+    /// ```
+    ///
+    /// Without [`Sodg::index_parents`] it scans all vertices of the graph.
+    pub fn parents(&self, v: usize) -> impl Iterator<Item = (usize, Label)> + '_ {
+        if self.indexed {
+            Either::Left(
+                self.vertex(v)
+                    .ok()
+                    .into_iter()
+                    .flat_map(|vtx| vtx.parents.iter().copied()),
+            )
+        } else {
+            Either::Right(
+                self.vertices
+                    .iter()
+                    .filter(|(_, vtx)| vtx.branch != BRANCH_NONE)
+                    .flat_map(move |(u, vtx)| {
+                        vtx.edges
+                            .iter()
+                            .filter(move |(_, to)| **to == v)
+                            .map(move |(a, _)| (u, *a))
+                    }),
+            )
+        }
+    }
+
+    /// Count the edges pointing to the vertex `v`.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(42);
+    /// g.bind(0, 42, Label::Alpha(0));
+    /// g.bind(0, 42, Label::Alpha(1));
+    /// assert_eq!(2, g.in_degree(42));
+    /// assert_eq!(0, g.in_degree(0));
+    /// ```
+    #[must_use]
+    pub fn in_degree(&self, v: usize) -> usize {
+        if self.indexed {
+            self.vertex(v).map_or(0, |vtx| vtx.parents.len())
+        } else {
+            self.parents(v).count()
+        }
+    }
+
+    /// Register the edge `a` from `v` to `to` in the index of parents,
+    /// and among the weak edges of `to`, if `a` is weak.
+    pub(crate) fn link(&mut self, v: usize, a: Label, to: usize) {
+        let weak = self.is_weak(a);
+        self.touch(to);
+        if let Some(vtx) = self.vertices.get_mut(to) {
+            if self.indexed {
+                vtx.parents.push((v, a));
+            }
+            if weak {
+                vtx.weak.push((v, a));
+            }
+        }
+    }
+
+    /// Unregister the edge `a` from `v` to `to` in the index of parents,
+    /// and among the weak edges of `to`.
+    pub(crate) fn unlink(&mut self, v: usize, a: Label, to: usize) {
+        self.touch(to);
+        if let Some(vtx) = self.vertices.get_mut(to) {
+            if self.indexed {
+                vtx.parents.retain(|p| *p != (v, a));
+            }
+            vtx.weak.retain(|p| *p != (v, a));
+        }
+    }
+
+    /// Unregister all edges departing from the vertex `v`, which is being
+    /// destroyed, and return how many of them there were.
+    pub(crate) fn unlink_all(&mut self, v: usize) -> usize {
+        let edges: Vec<(Label, usize)> = self.vertices[v]
+            .edges
+            .iter()
+            .map(|(a, to)| (*a, *to))
+            .collect();
+        for (a, to) in &edges {
+            self.unlink(v, *a, *to);
+        }
+        edges.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Dangling;
+
+    fn both() -> [Sodg<16>; 2] {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.index_parents();
+        [Sodg::empty(256), g]
+    }
+
+    #[test]
+    fn finds_parents() {
+        for mut g in both() {
+            g.add(0);
+            g.add(1);
+            g.add(2);
+            g.bind(0, 2, Label::Alpha(0));
+            g.bind(1, 2, Label::Alpha(1));
+            let mut parents: Vec<(usize, Label)> = g.parents(2).collect();
+            parents.sort();
+            assert_eq!(vec![(0, Label::Alpha(0)), (1, Label::Alpha(1))], parents);
+            assert_eq!(2, g.in_degree(2));
+        }
+    }
+
+    #[test]
+    fn forgets_replaced_edge() {
+        for mut g in both() {
+            g.add(0);
+            g.add(1);
+            g.add(2);
+            g.bind(0, 1, Label::Alpha(0));
+            g.bind(0, 2, Label::Alpha(0));
+            assert_eq!(0, g.in_degree(1));
+            assert_eq!(1, g.in_degree(2));
+        }
+    }
+
+    #[test]
+    fn forgets_unbound_edge() {
+        for mut g in both() {
+            g.add(0);
+            g.add(1);
+            g.bind(0, 1, Label::Alpha(0));
+            g.bind(0, 1, Label::Alpha(1));
+            g.unbind(0, Label::Alpha(0));
+            assert_eq!(vec![(0, Label::Alpha(1))], g.parents(1).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn forgets_removed_vertex() {
+        for mut g in both() {
+            g.add(0);
+            g.add(1);
+            g.add(2);
+            g.bind(0, 1, Label::Alpha(0));
+            g.bind(1, 2, Label::Alpha(0));
+            g.bind(0, 2, Label::Alpha(1));
+            g.remove(1, Dangling::Unbind);
+            assert_eq!(vec![(0, Label::Alpha(1))], g.parents(2).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn indexes_existing_edges() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        g.index_parents();
+        assert_eq!(1, g.in_degree(1));
+        g.unbind(0, Label::Alpha(0));
+        assert_eq!(0, g.in_degree(1));
+    }
+}
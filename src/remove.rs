@@ -0,0 +1,212 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::{Dangling, Label, Sodg, SodgError, Vertex};
+
+impl<const N: usize> Sodg<N> {
+    /// Remove the vertex `v` from the graph, together with all edges
+    /// departing from it and pointing to it.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Dangling, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(42);
+    /// g.bind(0, 42, Label::Alpha(0));
+    /// g.remove(42, Dangling::Unbind);
+    /// assert!(g.kid(0, Label::Alpha(0)).is_none());
+    /// assert_eq!(1, g.len());
+    /// ```
+    ///
+    /// The edges pointing to the vertex are returned. The ID of the vertex
+    /// will be returned by [`Sodg::next_id`] again.
+    ///
+    /// # Panics
+    ///
+    /// If [`Sodg::try_remove`] returns an error, it will panic.
+    pub fn remove(&mut self, v: usize, policy: Dangling) -> Vec<(usize, Label)> {
+        self.try_remove(v, policy).unwrap()
+    }
+
+    /// Remove the vertex `v` from the graph, together with all edges
+    /// departing from it and pointing to it, or explain why it's impossible.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Dangling, Label, Sodg, SodgError};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(42);
+    /// g.bind(0, 42, Label::Alpha(0));
+    /// assert!(matches!(
+    ///     g.try_remove(42, Dangling::Report),
+    ///     Err(SodgError::Dangling { v: 42, .. })
+    /// ));
+    /// assert_eq!(vec![(0, Label::Alpha(0))], g.try_remove(42, Dangling::Unbind).unwrap());
+    /// ```
+    ///
+    /// The kids of `v`, which are not reachable from its parents anymore,
+    /// stay alive in branches of their own, exactly as after [`Sodg::unbind`],
+    /// until their data is read by [`Sodg::data`] or they are destroyed
+    /// by [`Sodg::collect`]. If an error is returned, the graph stays intact.
+    ///
+    /// # Errors
+    ///
+    /// If vertex `v` is absent, an `Err` will be returned.
+    ///
+    /// If there are edges pointing to `v` and the `policy` is [`Dangling::Report`],
+    /// an `Err` will be returned.
+    ///
+    /// If the branch of `v` has to be split and there are no free branches left,
+    /// an `Err` will be returned.
+    pub fn try_remove(
+        &mut self,
+        v: usize,
+        policy: Dangling,
+    ) -> Result<Vec<(usize, Label)>, SodgError> {
+        self.vertex(v)?;
+        let incoming: Vec<(usize, Label)> = self.parents(v).collect();
+        if policy == Dangling::Report && !incoming.is_empty() {
+            return Err(SodgError::Dangling { v, edges: incoming });
+        }
+        let before = self.vertices[v].clone();
+        self.touch(v);
+        for (u, a) in &incoming {
+            self.touch(*u);
+            self.vertices[*u].edges.remove(a);
+        }
+        self.vertices[v].edges.clear();
+        #[cfg(feature = "gc")]
+        if let Err(e) = self.leave(v, &before, &incoming) {
+            for (u, a) in &incoming {
+                self.vertices[*u].edges.insert(*a, v);
+            }
+            self.vertices.insert(v, before);
+            return Err(e);
+        }
+        for (a, to) in before.edges.iter() {
+            self.unlink(v, *a, *to);
+        }
+        let mut empty = Vertex::empty();
+        empty.listed = before.listed;
+        self.vertices.insert(v, empty);
+        self.roots.remove(&v);
+        self.alive -= 1;
+        self.arrows -= incoming.len() + before.edges.len();
+        self.free(v);
+        #[cfg(debug_assertions)]
+        trace!(
+            "#remove: vertex ν{v} removed, {} edges pointed to it",
+            incoming.len()
+        );
+        Ok(incoming)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Liveness;
+
+    #[test]
+    fn removes_simple_vertex() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.remove(0, Dangling::Report);
+        assert_eq!(0, g.len());
+        assert!(g.try_kids(0).is_err());
+    }
+
+    #[test]
+    fn reports_dangling_edges() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        let r = g.try_remove(1, Dangling::Report);
+        assert!(
+            matches!(r, Err(SodgError::Dangling { v: 1, edges }) if edges == vec![(0, Label::Alpha(0))])
+        );
+        assert_eq!(2, g.len());
+        assert_eq!(1, g.kid(0, Label::Alpha(0)).unwrap());
+    }
+
+    #[test]
+    fn removes_incoming_edges() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        g.add(2);
+        g.bind(2, 1, Label::Alpha(1));
+        g.remove(1, Dangling::Unbind);
+        assert!(g.kid(0, Label::Alpha(0)).is_none());
+        assert!(g.kid(2, Label::Alpha(1)).is_none());
+        assert_eq!(2, g.len());
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn collects_orphan_kids() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        g.add(2);
+        g.bind(1, 2, Label::Alpha(0));
+        g.add(3);
+        g.bind(2, 3, Label::Alpha(0));
+        g.put(3, &crate::Hex::from(42));
+        g.remove(1, Dangling::Unbind);
+        assert_eq!(3, g.len());
+        assert_eq!(0, g.stores[2]);
+        g.data(3);
+        assert_eq!(1, g.len());
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn adjusts_stores() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        g.put(1, &crate::Hex::from(42));
+        assert_eq!(1, g.stores[2]);
+        g.remove(1, Dangling::Unbind);
+        assert_eq!(0, g.stores[2]);
+    }
+
+    #[test]
+    fn keeps_orphan_kid_alive() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        for v in 0..3 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(1, 2, Label::Alpha(0));
+        g.remove(1, Dangling::Unbind);
+        assert_eq!(Liveness::Alive, g.liveness(2));
+        g.bind(0, 2, Label::Alpha(0));
+        assert_eq!(Some(2), g.kid(0, Label::Alpha(0)));
+        assert_eq!(2, g.len());
+    }
+
+    #[test]
+    fn frees_id() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.add(2);
+        assert_eq!(3, g.next_id());
+        g.remove(1, Dangling::Unbind);
+        assert_eq!(1, g.next_id());
+        assert_eq!(4, g.next_id());
+    }
+}
@@ -5,19 +5,18 @@ use std::collections::HashMap;
 use std::str::FromStr as _;
 use std::sync::LazyLock as Lazy;
 
-use anyhow::{Context as _, Result, bail};
 use log::trace;
 use regex::Regex;
 
-use crate::{Hex, Script};
+use crate::{Dangling, Hex, Script, SodgError};
 use crate::{Label, Sodg};
 
 impl Script {
     /// Make a new one, parsing a string with instructions.
     ///
     /// Instructions
-    /// must be separated by semicolon. There are just three of them
-    /// possible: `ADD`, `BIND`, and `PUT`. The arguments must be
+    /// must be separated by semicolon. There are just five of them
+    /// possible: `ADD`, `BIND`, `PUT`, `UNBIND`, and `DEL`. The arguments must be
     /// separated by a comma. An argument may either be 1) a positive integer
     /// (possibly prepended by `ν`),
     /// 2) a variable started with `$`, 3) an attribute name, or
@@ -50,13 +49,21 @@ impl Script {
     ///
     /// # Errors
     ///
-    /// If impossible to deploy, an error will be returned.
-    pub fn deploy_to<const N: usize>(&mut self, g: &mut Sodg<N>) -> Result<usize> {
+    /// If a command can't be parsed, [`SodgError::ScriptSyntax`] will be returned.
+    /// If a label or data can't be parsed, or the graph refuses to accept a command,
+    /// [`SodgError::Script`] will be returned, with the original error inside.
+    pub fn deploy_to<const N: usize>(&mut self, g: &mut Sodg<N>) -> Result<usize, SodgError> {
         let mut pos = 0;
         for cmd in &self.commands() {
             trace!("#deploy_to: deploying command no.{} '{}'...", pos + 1, cmd);
-            self.deploy_one(cmd, g)
-                .with_context(|| format!("Failure at the command no.{pos}: '{cmd}'"))?;
+            self.deploy_one(pos, cmd, g).map_err(|e| match e {
+                SodgError::ScriptSyntax { .. } => e,
+                _ => SodgError::Script {
+                    command: cmd.clone(),
+                    position: pos,
+                    source: Box::new(e),
+                },
+            })?;
             pos += 1;
         }
         Ok(pos)
@@ -75,16 +82,24 @@ impl Script {
             .collect()
     }
 
-    /// Deploy a single command to the [`Sodg`].
+    /// Deploy a single command to the [`Sodg`], where `pos` is the position
+    /// of the command in the script.
     ///
     /// # Errors
     ///
     /// If impossible to deploy, an error will be returned.
-    fn deploy_one<const N: usize>(&mut self, cmd: &str, g: &mut Sodg<N>) -> Result<()> {
+    fn deploy_one<const N: usize>(
+        &mut self,
+        pos: usize,
+        cmd: &str,
+        g: &mut Sodg<N>,
+    ) -> Result<(), SodgError> {
         static LINE: Lazy<Regex> = Lazy::new(|| Regex::new("^([A-Z]+) *\\(([^)]*)\\)$").unwrap());
-        let cap = LINE
-            .captures(cmd)
-            .with_context(|| format!("Can't parse '{cmd}'"))?;
+        let syntax = || SodgError::ScriptSyntax {
+            command: cmd.to_string(),
+            position: pos,
+        };
+        let cap = LINE.captures(cmd).ok_or_else(syntax)?;
         let args: Vec<String> = cap[2]
             .split(',')
             .map(str::trim)
@@ -93,39 +108,71 @@ impl Script {
             .collect();
         match &cap[1] {
             "ADD" => {
-                let v = self.parse(args.first().context("V is expected")?, g)?;
-                g.add(v);
+                let v = args
+                    .first()
+                    .and_then(|a| self.parse(a, g))
+                    .ok_or_else(syntax)?;
+                g.try_add(v)?;
             }
             "BIND" => {
-                let v1 = self.parse(args.first().context("V1 is expected")?, g)?;
-                let v2 = self.parse(args.get(1).context("V2 is expected")?, g)?;
-                let a = Label::from_str(args.get(2).context("Label is expected")?.as_str())?;
-                g.bind(v1, v2, a);
+                let v1 = args
+                    .first()
+                    .and_then(|a| self.parse(a, g))
+                    .ok_or_else(syntax)?;
+                let v2 = args
+                    .get(1)
+                    .and_then(|a| self.parse(a, g))
+                    .ok_or_else(syntax)?;
+                let a = args
+                    .get(2)
+                    .ok_or_else(syntax)
+                    .and_then(|a| Label::from_str(a))?;
+                g.try_bind(v1, v2, a)?;
             }
             "PUT" => {
-                let v = self.parse(args.first().context("V is expected")?, g)?;
-                let d = Self::parse_data(args.get(1).context("Data is expected")?)?;
-                g.put(v, &d);
+                let v = args
+                    .first()
+                    .and_then(|a| self.parse(a, g))
+                    .ok_or_else(syntax)?;
+                let d = args
+                    .get(1)
+                    .ok_or_else(syntax)
+                    .and_then(|a| Self::parse_data(a))?;
+                g.try_put(v, &d)?;
+            }
+            "UNBIND" => {
+                let v = args
+                    .first()
+                    .and_then(|a| self.parse(a, g))
+                    .ok_or_else(syntax)?;
+                let a = args
+                    .get(1)
+                    .ok_or_else(syntax)
+                    .and_then(|a| Label::from_str(a))?;
+                g.try_unbind(v, a)?;
+            }
+            "DEL" => {
+                let v = args
+                    .first()
+                    .and_then(|a| self.parse(a, g))
+                    .ok_or_else(syntax)?;
+                g.try_remove(v, Dangling::Unbind)?;
             }
-            cmd => {
-                bail!("Unknown command: {cmd}");
+            _ => {
+                return Err(syntax());
             }
         }
         Ok(())
     }
 
-    /// Parse data.
-    ///
-    /// # Errors
-    ///
-    /// If impossible to parse, an error will be returned.
-    fn parse_data(s: &str) -> Result<Hex> {
+    /// Parse data, or explain why it's impossible.
+    fn parse_data(s: &str) -> Result<Hex, SodgError> {
         static DATA_STRIP: Lazy<Regex> = Lazy::new(|| Regex::new("[ \t\n\r\\-]").unwrap());
         static DATA: Lazy<Regex> =
             Lazy::new(|| Regex::new("^[0-9A-Fa-f]{2}([0-9A-Fa-f]{2})*$").unwrap());
         let d: &str = &DATA_STRIP.replace_all(s, "");
         if !DATA.is_match(d) {
-            bail!("Can't parse data '{s}'");
+            return Err(SodgError::BadHexLiteral(s.to_string()));
         }
         let bytes: Vec<u8> = (0..d.len())
             .step_by(2)
@@ -134,24 +181,19 @@ impl Script {
         Ok(Hex::from_vec(bytes))
     }
 
-    /// Parse `$ν5` into `5`, and `ν23` into `23`, and `42` into `42`.
-    ///
-    /// # Errors
-    ///
-    /// If impossible to parse, an error will be returned.
-    fn parse<const N: usize>(&mut self, s: &str, g: &mut Sodg<N>) -> Result<usize> {
-        let head = s.chars().next().context("Empty identifier")?;
+    /// Parse `$ν5` into `5`, and `ν23` into `23`, and `42` into `42`,
+    /// or return `None` if impossible.
+    fn parse<const N: usize>(&mut self, s: &str, g: &mut Sodg<N>) -> Option<usize> {
+        let head = s.chars().next()?;
         if head == '$' || head == 'ν' {
             let tail: String = s.chars().skip(1).collect::<Vec<_>>().into_iter().collect();
             if head == '$' {
-                Ok(*self.vars.entry(tail).or_insert_with(|| g.next_id()))
+                Some(*self.vars.entry(tail).or_insert_with(|| g.next_id()))
             } else {
-                Ok(usize::from_str(tail.as_str())
-                    .with_context(|| format!("Parsing of '{s}' failed"))?)
+                usize::from_str(tail.as_str()).ok()
             }
         } else {
-            let v = usize::from_str(s).with_context(|| format!("Parsing of '{s}' failed"))?;
-            Ok(v)
+            usize::from_str(s).ok()
         }
     }
 }
@@ -175,4 +217,95 @@ mod tests {
         assert_eq!("привет", g.data(1).unwrap().to_utf8().unwrap());
         assert_eq!(1, g.kid(0, Label::from_str("foo").unwrap()).unwrap());
     }
+
+    #[test]
+    fn unbinds_edge() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        let mut s = Script::from_str("ADD(0); ADD(1); BIND(0, 1, foo); UNBIND(0, foo);");
+        assert_eq!(4, s.deploy_to(&mut g).unwrap());
+        assert!(g.kid(0, Label::from_str("foo").unwrap()).is_none());
+    }
+
+    #[test]
+    fn deletes_vertex() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        let mut s = Script::from_str("ADD(0); ADD(1); BIND(0, 1, foo); DEL(1);");
+        assert_eq!(4, s.deploy_to(&mut g).unwrap());
+        assert_eq!(1, g.len());
+        assert!(g.kid(0, Label::from_str("foo").unwrap()).is_none());
+    }
+
+    #[test]
+    fn reports_broken_command() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        let mut s = Script::from_str("ADD(0); BIND(0, 1); ADD(1);");
+        assert!(matches!(
+            s.deploy_to(&mut g),
+            Err(SodgError::ScriptSyntax { position: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn reports_graph_failure() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        let mut s = Script::from_str("ADD(0); PUT(1, CA-FE);");
+        match s.deploy_to(&mut g) {
+            Err(SodgError::Script {
+                position, source, ..
+            }) => {
+                assert_eq!(1, position);
+                assert!(matches!(*source, SodgError::VertexAbsent(1)));
+            }
+            r => panic!("Unexpected {r:?}"),
+        }
+    }
+
+    #[test]
+    fn reports_position_of_failed_bind() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        let mut s = Script::from_str("ADD(0); ADD(1); BIND(0, 2, foo);");
+        let e = s.deploy_to(&mut g).unwrap_err();
+        assert!(matches!(
+            e,
+            SodgError::Script {
+                position: 2,
+                ref command,
+                ..
+            } if command == "BIND(0, 2, foo)"
+        ));
+        assert_eq!(
+            "Failure at the command no.2: 'BIND(0, 2, foo)': Can't find ν2",
+            e.to_string()
+        );
+    }
+
+    #[test]
+    fn reports_position_of_bad_data() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        let mut s = Script::from_str("ADD(0); PUT(0, CA-FE); PUT(0, XY-Z);");
+        match s.deploy_to(&mut g) {
+            Err(SodgError::Script {
+                position, source, ..
+            }) => {
+                assert_eq!(2, position);
+                assert!(matches!(*source, SodgError::BadHexLiteral(ref d) if d == "XY-Z"));
+            }
+            r => panic!("Unexpected {r:?}"),
+        }
+    }
+
+    #[test]
+    fn reports_position_of_bad_label() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        let mut s = Script::from_str("ADD(0); ADD(1); BIND(0, 1, foo-bar-long);");
+        match s.deploy_to(&mut g) {
+            Err(SodgError::Script {
+                position, source, ..
+            }) => {
+                assert_eq!(2, position);
+                assert!(matches!(*source, SodgError::LabelTooLong(_)));
+            }
+            r => panic!("Unexpected {r:?}"),
+        }
+    }
 }
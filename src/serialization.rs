@@ -5,10 +5,9 @@ use std::fs;
 use std::path::Path;
 use std::time::Instant;
 
-use anyhow::{Context as _, Result};
 use log::trace;
 
-use crate::Sodg;
+use crate::{Sodg, SodgError};
 
 impl<const N: usize> Sodg<N> {
     /// Save the entire [`Sodg`] into a binary file.
@@ -19,13 +18,12 @@ impl<const N: usize> Sodg<N> {
     ///
     /// # Errors
     ///
-    /// If impossible to save, an error will be returned.
-    pub fn save(&self, path: &Path) -> Result<usize> {
+    /// If impossible to save, [`SodgError::Io`] or [`SodgError::Encode`] will be returned.
+    pub fn save(&self, path: &Path) -> Result<usize, SodgError> {
         let start = Instant::now();
-        let bytes: Vec<u8> = bincode::serde::encode_to_vec(self, bincode::config::legacy())
-            .context("Failed to serialize")?;
+        let bytes: Vec<u8> = bincode::serde::encode_to_vec(self, bincode::config::legacy())?;
         let size = bytes.len();
-        fs::write(path, bytes).with_context(|| format!("Can't write to {}", path.display()))?;
+        fs::write(path, bytes)?;
         trace!(
             "Serialized {} vertices ({} bytes) to {} in {:?}",
             self.len(),
@@ -41,15 +39,12 @@ impl<const N: usize> Sodg<N> {
     ///
     /// # Errors
     ///
-    /// If impossible to load, an error will be returned.
-    pub fn load(path: &Path) -> Result<Self> {
+    /// If impossible to load, [`SodgError::Io`] or [`SodgError::Decode`] will be returned.
+    pub fn load(path: &Path) -> Result<Self, SodgError> {
         let start = Instant::now();
-        let bytes =
-            fs::read(path).with_context(|| format!("Can't read from {}", path.display()))?;
+        let bytes = fs::read(path)?;
         let size = bytes.len();
-        let sodg: Self = bincode::serde::decode_from_slice(&bytes, bincode::config::legacy())
-            .with_context(|| format!("Can't deserialize from {}", path.display()))?
-            .0;
+        let sodg: Self = bincode::serde::decode_from_slice(&bytes, bincode::config::legacy())?.0;
         trace!(
             "Deserialized {} vertices ({} bytes) from {} in {:?}",
             sodg.len(),
@@ -93,4 +88,32 @@ mod tests {
         let after: Sodg<1> = Sodg::load(file.as_path()).unwrap();
         assert_eq!(g.inspect(0).unwrap(), after.inspect(0).unwrap());
     }
+
+    #[test]
+    fn keeps_next_id_after_load() {
+        let mut g: Sodg<16> = Sodg::empty(256);
+        g.add(0);
+        g.add(1);
+        g.add(5);
+        g.remove(1, crate::Dangling::Unbind);
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("foo.sodg");
+        g.save(file.as_path()).unwrap();
+        let mut after: Sodg<16> = Sodg::load(file.as_path()).unwrap();
+        assert_eq!(1, after.next_id());
+        assert_eq!(2, after.next_id());
+        assert_eq!(3, after.next_id());
+        assert_eq!(4, after.next_id());
+        assert_eq!(6, after.next_id());
+    }
+
+    #[test]
+    fn fails_to_load_absent_file() {
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("absent.sodg");
+        assert!(matches!(
+            Sodg::<16>::load(file.as_path()),
+            Err(SodgError::Io(_))
+        ));
+    }
 }
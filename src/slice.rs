@@ -3,10 +3,9 @@
 
 use std::collections::HashSet;
 
-use anyhow::Result;
 use log::trace;
 
-use crate::{Label, Sodg};
+use crate::{Label, Sodg, SodgError};
 
 impl<const N: usize> Sodg<N> {
     /// Take a slice of the graph, keeping only the vertex specified
@@ -16,7 +15,7 @@ impl<const N: usize> Sodg<N> {
     ///
     /// If impossible to slice, an error will be returned.
     #[allow(clippy::use_self)]
-    pub fn slice(&self, v: usize) -> Result<Self> {
+    pub fn slice(&self, v: usize) -> Result<Self, SodgError> {
         let g: Sodg<N> = self.slice_some(v, |_, _, _| true)?;
         trace!(
             "#slice: taken {} vertices out of {} at ν{v}",
@@ -33,12 +32,12 @@ impl<const N: usize> Sodg<N> {
     ///
     /// # Errors
     ///
-    /// There could be errors too.
-    ///
-    /// # Panics
-    ///
     /// If impossible to slice, an error will be returned.
-    pub fn slice_some(&self, v: usize, p: impl Fn(usize, usize, Label) -> bool) -> Result<Self> {
+    pub fn slice_some(
+        &self,
+        v: usize,
+        p: impl Fn(usize, usize, Label) -> bool,
+    ) -> Result<Self, SodgError> {
         let mut todo = HashSet::new();
         let mut done = HashSet::new();
         todo.insert(v);
@@ -49,7 +48,7 @@ impl<const N: usize> Sodg<N> {
             let before: Vec<usize> = todo.drain().collect();
             for v in before {
                 done.insert(v);
-                for e in &self.vertices.get(v).unwrap().edges {
+                for e in self.vertex(v)?.edges.iter() {
                     if done.contains(e.1) {
                         continue;
                     }
@@ -62,14 +61,17 @@ impl<const N: usize> Sodg<N> {
             }
         }
         let mut ng = Self::empty(self.vertices.capacity());
+        if self.indexed {
+            ng.index_parents();
+        }
         for (v1, vtx) in self.vertices.iter().filter(|(v, _)| done.contains(v)) {
             if done.contains(&v1) {
-                ng.add(v1);
+                ng.try_add(v1)?;
             }
-            for (k, v2) in &vtx.edges {
+            for (k, v2) in vtx.edges.iter() {
                 if done.contains(v2) {
-                    ng.add(*v2);
-                    ng.bind(v1, *v2, *k);
+                    ng.try_add(*v2)?;
+                    ng.try_bind(v1, *v2, *k)?;
                 }
             }
         }
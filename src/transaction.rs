@@ -0,0 +1,281 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use std::collections::HashMap;
+
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::{Journal, Sodg, SodgError};
+
+impl<const N: usize> Sodg<N> {
+    /// Start a transaction, which may be either committed
+    /// by [`Sodg::commit`] or rolled back by [`Sodg::rollback`].
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.begin();
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.put(1, &Hex::from(42));
+    /// g.rollback();
+    /// assert_eq!(1, g.len());
+    /// assert!(g.kid(0, Label::Alpha(0)).is_none());
+    /// ```
+    ///
+    /// The graph is not copied: a vertex or a branch is saved only when
+    /// it is about to be changed for the first time in the transaction, including
+    /// the changes made by garbage collection. Transactions can't be nested:
+    /// if one is already started, nothing happens.
+    pub fn begin(&mut self) {
+        if self.journal.is_some() {
+            return;
+        }
+        self.journal = Some(Box::new(Journal {
+            vertices: HashMap::new(),
+            #[cfg(feature = "gc")]
+            branches: HashMap::new(),
+            #[cfg(feature = "gc")]
+            total: self.branches.len(),
+            #[cfg(feature = "gc")]
+            idle: self.idle.clone(),
+            #[cfg(feature = "gc")]
+            max_branches: self.max_branches,
+            alive: self.alive,
+            arrows: self.arrows,
+            next_v: self.next_v,
+            indexed: self.indexed,
+            freed: self.freed.clone(),
+            skipped: self.skipped.clone(),
+            roots: self.roots.clone(),
+            collector: self.collector,
+            weak: self.weak.clone(),
+        }));
+    }
+
+    /// Keep all changes made since [`Sodg::begin`].
+    ///
+    /// If there is no transaction, nothing happens.
+    pub fn commit(&mut self) {
+        self.journal = None;
+    }
+
+    /// Restore the graph to the state it had at [`Sodg::begin`].
+    ///
+    /// The capacity of the graph is not restored. The observers registered
+    /// by [`Sodg::observe`] are not told about the rollback.
+    /// If there is no transaction, nothing happens.
+    pub fn rollback(&mut self) {
+        let Some(j) = self.journal.take() else {
+            return;
+        };
+        #[cfg(debug_assertions)]
+        trace!("#rollback: {} vertices restored", j.vertices.len());
+        for (v, before) in j.vertices {
+            let _ = self.grow(v);
+            match before {
+                Some(vtx) => self.vertices.insert(v, vtx),
+                None => self.vertices.remove(v),
+            }
+        }
+        #[cfg(feature = "gc")]
+        {
+            self.branches.truncate(j.total);
+            self.stores.truncate(j.total);
+            for (b, (members, stores)) in j.branches {
+                self.branches[b] = members;
+                self.stores[b] = stores;
+            }
+            self.idle = j.idle;
+            self.max_branches = j.max_branches;
+        }
+        self.alive = j.alive;
+        self.arrows = j.arrows;
+        self.next_v = j.next_v;
+        self.indexed = j.indexed;
+        self.freed = j.freed;
+        self.skipped = j.skipped;
+        self.roots = j.roots;
+        self.collector = j.collector;
+        self.weak = j.weak;
+    }
+
+    /// Run `f` in a transaction, which is committed if `f` returns `Ok`,
+    /// and rolled back otherwise.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// let r = g.transaction(|tx| {
+    ///     tx.add(1);
+    ///     tx.try_bind(0, 1, Label::Alpha(0))?;
+    ///     tx.try_bind(0, 2, Label::Alpha(1))
+    /// });
+    /// assert!(r.is_err());
+    /// assert_eq!(1, g.len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `f` returns an `Err`, it is returned.
+    pub fn transaction<T, F>(&mut self, f: F) -> Result<T, SodgError>
+    where
+        F: FnOnce(&mut Self) -> Result<T, SodgError>,
+    {
+        self.begin();
+        let r = f(self);
+        if r.is_ok() {
+            self.commit();
+        } else {
+            self.rollback();
+        }
+        r
+    }
+
+    /// Save the vertex `v`, which is about to be changed, if there is a transaction.
+    pub(crate) fn touch(&mut self, v: usize) {
+        if let Some(j) = &mut self.journal {
+            j.vertices.entry(v).or_insert_with(|| {
+                if v < self.vertices.capacity() {
+                    self.vertices.get(v).cloned()
+                } else {
+                    None
+                }
+            });
+        }
+    }
+
+    /// Save the branch `b`, which is about to be changed, if there is a transaction.
+    #[cfg(feature = "gc")]
+    pub(crate) fn touch_branch(&mut self, b: usize) {
+        if let Some(j) = &mut self.journal
+            && b < j.total
+        {
+            j.branches
+                .entry(b)
+                .or_insert_with(|| (self.branches[b].clone(), self.stores[b]));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Collector, Dangling, Hex, Label, Liveness};
+
+    fn fingerprint<const N: usize>(g: &Sodg<N>) -> String {
+        format!(
+            "{:?} {:?} {} {} {:?} {:?}",
+            g.keys(),
+            g.edges().collect::<Vec<_>>(),
+            g.len(),
+            g.edge_count(),
+            g.keys()
+                .iter()
+                .map(|v| g.persistence(*v))
+                .collect::<Vec<_>>(),
+            g.roots().collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn rolls_back_simple_changes() {
+        let mut g: Sodg<16> = Sodg::empty(4);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        let before = fingerprint(&g);
+        g.begin();
+        g.add(2);
+        g.add(100);
+        g.bind(1, 2, Label::Alpha(0));
+        g.unbind(0, Label::Alpha(0));
+        g.put(0, &Hex::from(42));
+        g.set_root(0);
+        g.rollback();
+        assert_eq!(before, fingerprint(&g));
+        assert_eq!(Liveness::Absent, g.liveness(100));
+        assert_eq!(2, g.next_id());
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn rolls_back_garbage_collection() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        for v in 0..4 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(1, 2, Label::Alpha(0));
+        g.bind(2, 3, Label::Alpha(0));
+        g.put(3, &Hex::from(42));
+        let before = fingerprint(&g);
+        g.begin();
+        g.data(3);
+        assert!(g.is_empty());
+        g.rollback();
+        assert_eq!(before, fingerprint(&g));
+        assert_eq!(Some(crate::Persistence::Stored), g.persistence(3));
+        g.data(3);
+        assert!(g.is_empty());
+    }
+
+    #[test]
+    fn rolls_back_remove_and_collect() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.index_parents();
+        g.set_collector(Collector::Tracing);
+        for v in 0..4 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(2, 3, Label::Alpha(0));
+        g.set_root(0);
+        let before = fingerprint(&g);
+        g.begin();
+        g.remove(1, Dangling::Unbind);
+        assert_eq!(vec![2, 3], g.collect());
+        g.rollback();
+        assert_eq!(before, fingerprint(&g));
+        assert_eq!(vec![(0, Label::Alpha(0))], g.parents(1).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn commits_changes() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.transaction(|tx| {
+            tx.add(0);
+            tx.add(1);
+            tx.try_bind(0, 1, Label::Alpha(0))
+        })
+        .unwrap();
+        g.rollback();
+        assert_eq!(2, g.len());
+        assert_eq!(Some(1), g.kid(0, Label::Alpha(0)));
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn rolls_back_claimed_branches() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        for v in 0..4 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        let branches = g.branches.len();
+        g.begin();
+        g.bind(2, 3, Label::Alpha(0));
+        g.bind(1, 2, Label::Alpha(0));
+        g.rollback();
+        assert_eq!(branches, g.branches.len());
+        assert_ne!(g.vertices[1].branch, g.vertices[2].branch);
+        assert_eq!(2, g.branches[g.vertices[0].branch].len());
+    }
+}
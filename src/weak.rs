@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
+// SPDX-License-Identifier: MIT
+
+use crate::{BRANCH_NONE, Label, Sodg};
+
+impl<const N: usize> Sodg<N> {
+    /// Make all edges labeled `a` weak.
+    ///
+    /// A weak edge doesn't keep the vertex it points to alive: it neither
+    /// brings the vertex into the branch of its departure vertex, nor makes
+    /// it reachable for [`Sodg::collect`]. When the vertex is destroyed as garbage,
+    /// the weak edges pointing to it are removed. For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.weaken(Label::Greek('ρ'));
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(2, 1, Label::Greek('ρ'));
+    /// g.put(1, &Hex::from(42));
+    /// g.data(1);
+    /// # #[cfg(feature = "gc")]
+    /// assert!(g.kid(2, Label::Greek('ρ')).is_none());
+    /// ```
+    ///
+    /// The edges with this label, which are already made, become weak too:
+    /// they are removed when the vertices they point to are destroyed.
+    /// However, the branches they have already joined stay joined.
+    pub fn weaken(&mut self, a: Label) {
+        if !self.weak.insert(a) {
+            return;
+        }
+        let edges: Vec<(usize, usize)> = self
+            .vertices
+            .iter()
+            .filter(|(_, vtx)| vtx.branch != BRANCH_NONE)
+            .filter_map(|(u, vtx)| vtx.edges.get(&a).map(|to| (u, *to)))
+            .collect();
+        for (u, to) in edges {
+            self.touch(to);
+            self.vertices[to].weak.push((u, a));
+        }
+    }
+
+    /// Are the edges labeled `a` weak? See [`Sodg::weaken`].
+    #[must_use]
+    pub fn is_weak(&self, a: Label) -> bool {
+        self.weak.contains(&a)
+    }
+
+    /// Remove the weak edges pointing to the vertices `vs`,
+    /// which were just destroyed as garbage.
+    pub(crate) fn bury(&mut self, vs: &[usize]) {
+        for v in vs {
+            self.touch(*v);
+            let weak = std::mem::take(&mut self.vertices[*v].weak);
+            for (u, a) in weak {
+                if self.vertex(u).is_ok() && self.vertices[u].edges.get(&a) == Some(v) {
+                    self.touch(u);
+                    self.vertices[u].edges.remove(&a);
+                    self.unlink(u, a, *v);
+                    self.arrows -= 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Collector, Dangling, Liveness};
+
+    fn rho() -> Label {
+        Label::Greek('ρ')
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn forgets_parents_destroyed_by_data() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.index_parents();
+        g.weaken(rho());
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        g.add(2);
+        g.bind(1, 2, rho());
+        g.put(1, &crate::Hex::from(42));
+        g.data(1);
+        assert_eq!(0, g.parents(2).count());
+        assert_eq!(0, g.in_degree(2));
+        assert!(g.try_remove(2, Dangling::Report).is_ok());
+        assert!(g.is_empty());
+        assert_eq!(0, g.edge_count());
+    }
+
+    #[test]
+    fn forgets_edges_weakened_after_bind() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.index_parents();
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, rho());
+        g.weaken(rho());
+        g.set_root(0);
+        assert_eq!(vec![1], g.collect());
+        assert!(g.kid(0, rho()).is_none());
+        assert_eq!(0, g.edge_count());
+        assert_eq!(vec![0], g.keys());
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn forgets_parents_orphaned_by_remove() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.index_parents();
+        g.weaken(rho());
+        for v in 0..4 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(1, 2, Label::Alpha(0));
+        g.bind(2, 3, rho());
+        g.remove(1, Dangling::Unbind);
+        assert_eq!(Liveness::Alive, g.liveness(2));
+        assert_eq!(1, g.in_degree(3));
+        g.set_root(0);
+        g.set_root(3);
+        g.collect();
+        assert_eq!(Liveness::Collected, g.liveness(2));
+        assert_eq!(0, g.in_degree(3));
+        g.remove(3, Dangling::Unbind);
+        assert_eq!(vec![0], g.keys());
+        assert_eq!(0, g.edge_count());
+    }
+
+    #[test]
+    fn collects_parent_by_tracing() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.weaken(rho());
+        g.set_collector(Collector::Tracing);
+        g.add(0);
+        g.add(1);
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(1, 0, rho());
+        g.set_root(1);
+        assert_eq!(vec![0], g.collect());
+        assert!(g.kid(1, rho()).is_none());
+        assert_eq!(0, g.edge_count());
+        assert_eq!(Liveness::Alive, g.liveness(1));
+    }
+
+    #[test]
+    fn removes_weak_edge_like_others() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.index_parents();
+        g.weaken(rho());
+        g.add(0);
+        g.add(1);
+        g.bind(1, 0, rho());
+        assert_eq!(vec![(1, rho())], g.parents(0).collect::<Vec<_>>());
+        assert_eq!(Some(0), g.unbind(1, rho()));
+        g.bind(1, 0, rho());
+        g.remove(0, Dangling::Unbind);
+        assert_eq!(0, g.edge_count());
+        assert!(g.vertices[0].weak.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn keeps_weak_target_out_of_branch() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.weaken(rho());
+        for v in 0..3 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(1, 2, rho());
+        g.put(1, &crate::Hex::from(42));
+        assert_ne!(g.vertices[1].branch, g.vertices[2].branch);
+        g.data(1);
+        assert_eq!(vec![2], g.keys());
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn drops_weak_edge_to_garbage() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.weaken(rho());
+        for v in 0..3 {
+            g.add(v);
+        }
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(2, 1, rho());
+        g.put(1, &crate::Hex::from(42));
+        g.data(1);
+        assert_eq!(vec![2], g.keys());
+        assert!(g.kid(2, rho()).is_none());
+        assert_eq!(g.edges().count(), g.edge_count());
+        g.add(1);
+        g.add(0);
+        g.bind(0, 1, Label::Alpha(0));
+        assert!(g.kid(2, rho()).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "gc")]
+    fn unbinds_weak_edge_without_split() {
+        let mut g: Sodg<16> = Sodg::empty(16);
+        g.weaken(rho());
+        g.add(0);
+        g.add(1);
+        g.add(2);
+        g.bind(0, 1, Label::Alpha(0));
+        g.bind(1, 0, rho());
+        g.bind(1, 2, Label::Alpha(0));
+        g.unbind(1, rho());
+        assert_eq!(3, g.len());
+    }
+}
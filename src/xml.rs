@@ -1,11 +1,10 @@
 // SPDX-FileCopyrightText: Copyright (c) 2022-2025 Objectionary.com
 // SPDX-License-Identifier: MIT
 
-use anyhow::Result;
 use itertools::Itertools as _;
 use xml_builder::{XMLBuilder, XMLElement, XMLVersion};
 
-use crate::{Persistence, Sodg};
+use crate::{Persistence, Sodg, SodgError};
 
 impl<const N: usize> Sodg<N> {
     /// Make XML graph.
@@ -43,29 +42,30 @@ impl<const N: usize> Sodg<N> {
     /// # Errors
     ///
     /// If it's impossible to print it to XML, an [`Err`] may be returned. Problems may also
-    /// be caused by XML errors from the XML builder library.
-    pub fn to_xml(&self) -> Result<String> {
+    /// be caused by XML errors from the XML builder library, reported as [`SodgError::Xml`].
+    pub fn to_xml(&self) -> Result<String, SodgError> {
         let mut xml = XMLBuilder::new()
             .version(XMLVersion::XML1_1)
             .encoding("UTF-8".into())
             .build();
         let mut root = XMLElement::new("sodg");
-        for (v, vtx) in self
-            .vertices
-            .iter()
-            .sorted_by_key(|(v, _)| <usize>::clone(v))
-        {
+        let mut edges = itertools::put_back(self.edges());
+        for v in self.vertices() {
+            let vtx = &self.vertices[v];
             let mut v_node = XMLElement::new("v");
             v_node.add_attribute("id", v.to_string().as_str());
-            for e in vtx.edges.iter().sorted_by_key(|e| e.0) {
+            for (_, a, to) in edges
+                .peeking_take_while(|e| e.0 == v)
+                .sorted_by_key(|e| e.1)
+            {
                 let mut e_node = XMLElement::new("e");
-                e_node.add_attribute("a", e.0.to_string().as_str());
-                e_node.add_attribute("to", e.1.to_string().as_str());
+                e_node.add_attribute("a", a.to_string().as_str());
+                e_node.add_attribute("to", to.to_string().as_str());
                 v_node.add_child(e_node)?;
             }
             if vtx.persistence != Persistence::Empty {
                 let mut data_node = XMLElement::new("data");
-                data_node.add_text(vtx.data.print().replace('-', " "))?;
+                data_node.add_text(vtx.data.print_spaced())?;
                 v_node.add_child(data_node)?;
             }
             root.add_child(v_node)?;
@@ -73,7 +73,7 @@ impl<const N: usize> Sodg<N> {
         xml.set_root_element(root);
         let mut writer: Vec<u8> = Vec::new();
         xml.generate(&mut writer)?;
-        Ok(std::str::from_utf8(&writer)?.to_string())
+        Ok(String::from_utf8(writer)?)
     }
 }
 